@@ -0,0 +1,2 @@
+-- Your SQL goes here
+alter table frames add column note text DEFAULT null;
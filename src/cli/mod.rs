@@ -1,57 +1,102 @@
+use chrono::Duration;
 use chrono::NaiveDateTime;
-use chrono::ParseError;
 use dialoguer::theme::ColorfulTheme;
 use dialoguer::Confirm;
 
 use crate::config::load_config;
+use crate::error::{Error, Result};
 use crate::service::project::has_project;
 use crate::service::tags::has_tag;
 
-pub fn parse_to_datetime(s: &str) -> Result<NaiveDateTime, ParseError> {
-    let result = NaiveDateTime::parse_from_str(s, &load_config().datetime_format);
+pub fn parse_to_datetime(s: &str) -> Result<NaiveDateTime, String> {
+    let format = load_config()
+        .map_err(|error| error.to_string())?
+        .datetime_format;
+    let result = NaiveDateTime::parse_from_str(s, &format);
 
     if result.is_err() {
-        return NaiveDateTime::parse_from_str(
-            format!("{} 00:00", s).as_str(),
-            &load_config().datetime_format,
-        );
+        return NaiveDateTime::parse_from_str(format!("{} 00:00", s).as_str(), &format)
+            .map_err(|error| error.to_string());
     }
 
-    return result;
+    result.map_err(|error| error.to_string())
 }
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let (negative, value) = match s.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let mut duration = Duration::zero();
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+
+        let amount: i64 = number
+            .parse()
+            .map_err(|_| format!("Invalid duration '{}'", s))?;
+        let unit: i64 = match c {
+            'd' => 24 * 60 * 60,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(format!("Invalid duration unit '{}' in '{}'", c, s)),
+        };
+        // Duration panics beyond its range, so the seconds are checked first.
+        duration = amount
+            .checked_mul(unit)
+            .filter(|seconds| *seconds <= Duration::max_value().num_seconds())
+            .and_then(|seconds| duration.checked_add(&Duration::seconds(seconds)))
+            .ok_or_else(|| format!("Duration '{}' is too long", s))?;
+        number.clear();
+    }
+
+    if !number.is_empty() || value.is_empty() {
+        return Err(format!(
+            "Invalid duration '{}', use units like +15m, -1h or 1h30m",
+            s
+        ));
+    }
+
+    Ok(if negative { -duration } else { duration })
+}
+
 pub fn convert_tags(v: &str) -> Result<String, String> {
     if !v.starts_with('+') {
         return Err("Fail".to_string());
     }
-    return Ok(v.strip_prefix('+').unwrap().to_string());
+    Ok(v.strip_prefix('+').unwrap().to_string())
 }
 
-pub fn process_tags(tags: Vec<String>, confirm: bool) -> bool {
+pub fn process_tags(tags: Vec<String>, confirm: bool) -> Result<bool> {
     for tag in tags {
-        if !process_tag(tag, confirm) {
-            return false;
+        if !process_tag(tag, confirm)? {
+            return Ok(false);
         }
     }
-    true
+    Ok(true)
 }
 
-pub fn process_tag(tag: String, confirm: bool) -> bool {
+pub fn process_tag(tag: String, confirm: bool) -> Result<bool> {
     if confirm
-        && !has_tag(tag.to_string())
+        && !has_tag(tag.to_string())?
         && !Confirm::with_theme(&ColorfulTheme::default())
             .with_prompt(format!("Tag '{}' does not exist yet. Create it?", tag))
             .default(false)
             .interact()
-            .unwrap()
+            .map_err(Error::Prompt)?
     {
-        return false;
+        return Ok(false);
     }
-    true
+    Ok(true)
 }
 
-pub fn process_project(project: String, confirm: bool) -> bool {
+pub fn process_project(project: String, confirm: bool) -> Result<bool> {
     if confirm
-        && !has_project(project.to_string())
+        && !has_project(project.to_string())?
         && !Confirm::with_theme(&ColorfulTheme::default())
             .with_prompt(format!(
                 "Project '{}' does not exist yet. Create it?",
@@ -59,18 +104,18 @@ pub fn process_project(project: String, confirm: bool) -> bool {
             ))
             .default(false)
             .interact()
-            .unwrap()
+            .map_err(Error::Prompt)?
     {
-        return false;
+        return Ok(false);
     }
-    true
+    Ok(true)
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, Timelike};
 
-    use super::parse_to_datetime;
+    use super::{parse_duration, parse_to_datetime};
 
     #[test]
     fn parse_with_date() {
@@ -93,4 +138,27 @@ mod tests {
         assert_eq!(4, result.hour());
         assert_eq!(5, result.minute());
     }
+
+    #[test]
+    fn parse_durations() {
+        assert_eq!(15, parse_duration("+15m").unwrap().num_minutes());
+        assert_eq!(-60, parse_duration("-1h").unwrap().num_minutes());
+        assert_eq!(90, parse_duration("1h30m").unwrap().num_minutes());
+        assert_eq!(24 * 60, parse_duration("1d").unwrap().num_minutes());
+    }
+
+    #[test]
+    fn parse_invalid_durations() {
+        assert!(parse_duration("15").is_err());
+        assert!(parse_duration("+").is_err());
+        assert!(parse_duration("15x").is_err());
+        assert!(parse_duration("h").is_err());
+    }
+
+    #[test]
+    fn parse_too_long_durations() {
+        assert!(parse_duration("99999999999999d").is_err());
+        assert!(parse_duration("99999999999999999999s").is_err());
+        assert!(parse_duration("100000000000000d100000000000000d").is_err());
+    }
 }
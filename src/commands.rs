@@ -4,13 +4,35 @@ pub mod log;
 pub mod start;
 pub mod status;
 pub mod stop;
+pub mod completions;
+pub mod config;
+pub mod doctor;
+pub mod fill;
 pub mod frames;
+pub mod gaps;
+pub mod help;
+pub mod join;
+pub mod man;
+pub mod pomodoro;
+pub mod profile;
 pub mod projects;
 pub mod remove;
+pub mod search;
+pub mod shift;
+pub mod split;
 
-use std::io::Write;
+use std::env;
+use std::io::{ErrorKind, IsTerminal, Write};
+use std::process::{Command, Stdio};
 
-use anyhow::Result;
+use anyhow::{anyhow, Context, Result};
+use aze::cli::parse_duration;
+use aze::service::frame::stop_frame;
+use aze::service::idle::idle_frames;
+use chrono::Duration;
+use colored::Colorize;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::Confirm;
 
 use crate::config::{load_config, AppConfig};
 
@@ -18,10 +40,137 @@ pub struct Output<'a> {
     pub out: &'a mut dyn Write,
 }
 
+impl<'a> Output<'a> {
+    /// Creates the output and enables or disables colors for everything
+    /// written with `colored`.
+    pub fn new(out: &'a mut dyn Write, color: bool) -> Self {
+        colored::control::set_override(color);
+
+        Self { out }
+    }
+}
+
+/// Writes the content through `$PAGER`, falling back to `less -R`, if stdout
+/// is a terminal and the content doesn't fit on it.
+pub fn page(output: Output, content: &[u8]) -> Result<()> {
+    let lines = content.iter().filter(|byte| **byte == b'\n').count();
+    let fits = match console::Term::stdout().size_checked() {
+        Some((rows, _)) => lines < rows.into(),
+        None => true,
+    };
+
+    if fits || !std::io::stdout().is_terminal() {
+        output.out.write_all(content)?;
+        return Ok(());
+    }
+
+    let pager = env::var("PAGER")
+        .ok()
+        .filter(|pager| !pager.trim().is_empty())
+        .unwrap_or_else(|| "less -R".to_string());
+    let mut parts = pager.split_whitespace();
+    let mut child = Command::new(parts.next().unwrap())
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|error| anyhow!("Could not start pager '{}': {}", pager, error))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        match stdin.write_all(content) {
+            Err(error) if error.kind() != ErrorKind::BrokenPipe => return Err(error.into()),
+            _ => {}
+        }
+    }
+    child.wait()?;
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorChoice {
+    Always,
+    Never,
+    Auto,
+}
+
+impl ColorChoice {
+    /// Command line flags win over the `color` setting of the config.
+    pub fn new(color: bool, no_color: bool, config: &str) -> Self {
+        if color {
+            ColorChoice::Always
+        } else if no_color {
+            ColorChoice::Never
+        } else {
+            match config {
+                "always" => ColorChoice::Always,
+                "never" => ColorChoice::Never,
+                _ => ColorChoice::Auto,
+            }
+        }
+    }
+
+    /// In auto mode colors are used unless `NO_COLOR` is set or stdout is not
+    /// a terminal.
+    pub fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+                    && std::io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
 pub trait MyCommand {
     fn run(&self, output: Output) -> Result<()>;
 
-    fn config(&self) -> AppConfig {
-        load_config()
+    fn config(&self) -> Result<AppConfig> {
+        Ok(load_config()?)
+    }
+}
+
+pub fn max_frame_duration(config: &AppConfig) -> Result<Option<Duration>> {
+    config
+        .max_frame_duration
+        .as_deref()
+        .map(|value| {
+            parse_duration(value)
+                .map_err(|error| anyhow!("Invalid max_frame_duration in config: {}", error))
+        })
+        .transpose()
+}
+
+/// Asks whether running frames exceeding the configured maximum duration
+/// should be stopped. Only done when running in a terminal.
+pub fn prompt_idle_frames() -> Result<()> {
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        return Ok(());
     }
+
+    let config = load_config()?;
+    let max = match max_frame_duration(&config)? {
+        Some(max) => max,
+        None => return Ok(()),
+    };
+
+    for (frame, end) in idle_frames(&max)? {
+        let stop = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!(
+                "Project {} is running since {}. Stop it at {}?",
+                frame.project.purple(),
+                frame.start.format(&config.datetime_format),
+                end.format(&config.datetime_format)
+            ))
+            .default(true)
+            .interact()?;
+
+        if stop {
+            stop_frame(&frame, &end)
+                .with_context(|| format!("Could not stop project {}", frame.project))?;
+        }
+    }
+
+    Ok(())
 }
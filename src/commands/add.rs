@@ -29,8 +29,16 @@ pub struct AddSubcommand {
     pub to: NaiveDateTime,
 
     #[clap(
-        help = "Confirm addition of new project",
+        help = "Note which should be added to the activity.",
         display_order = 3,
+        short = 'n',
+        long = "note"
+    )]
+    pub note: Option<String>,
+
+    #[clap(
+        help = "Confirm addition of new project",
+        display_order = 4,
         short = 'c',
         long = "confirm-new-project"
     )]
@@ -38,7 +46,7 @@ pub struct AddSubcommand {
 
     #[clap(
         help = "Confirm addition of new tag",
-        display_order = 4,
+        display_order = 5,
         short = 'b',
         long = "confirm-new-tags"
     )]
@@ -47,10 +55,10 @@ pub struct AddSubcommand {
 
 impl MyCommand for AddSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
-        process_project(self.project.to_string(), self.confirm_project);
-        process_tags(self.tags.to_owned(), self.confirm_tags);
+        process_project(self.project.to_string(), self.confirm_project)?;
+        process_tags(self.tags.to_owned(), self.confirm_tags)?;
 
-        if frame_collides(&self.from, &self.to) {
+        if !self.config()?.allow_concurrent_frames && frame_collides(&self.from, &self.to)? {
             return Err(anyhow!(
                 "Frame already exist which overlaps with start and end"
             ));
@@ -66,16 +74,22 @@ impl MyCommand for AddSubcommand {
                 "".to_string()
             },
             self.from
-                .format(&self.config().datetime_format)
+                .format(&self.config()?.datetime_format)
                 .to_string()
                 .green(),
             self.to
-                .format(&self.config().datetime_format)
+                .format(&self.config()?.datetime_format)
                 .to_string()
                 .green()
         )?;
 
-        create_frame(&self.from, &self.to, &self.project, self.tags.to_owned());
+        create_frame(
+            &self.from,
+            &self.to,
+            &self.project,
+            self.tags.to_owned(),
+            self.note.as_deref(),
+        )?;
 
         Ok(())
     }
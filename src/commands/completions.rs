@@ -0,0 +1,220 @@
+use std::env;
+
+use anyhow::Result;
+use aze::service::frame::{find_all as find_all_frames, running_frames};
+use aze::service::project::find_all as find_all_projects;
+use aze::service::tags::find_all as find_all_tags;
+use clap::CommandFactory;
+use clap_complete::shells::{Bash, Fish, Zsh};
+
+use super::MyCommand;
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+const BASH_VALUES: &str = r#"
+_aze_with_values() {
+    local values
+    values=$(aze __complete -- "${COMP_WORDS[@]:0:COMP_CWORD+1}" 2>/dev/null)
+    if [[ -n "$values" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=( $(compgen -W "$values" -- "${COMP_WORDS[COMP_CWORD]}") )
+        return 0
+    fi
+    _aze "$@"
+}
+
+complete -F _aze_with_values -o bashdefault -o default aze
+"#;
+
+const ZSH_VALUES: &str = r#"_aze_with_values() {
+    local -a values
+    values=(${(f)"$(aze __complete -- "${(@)words[1,CURRENT]}" 2>/dev/null)"})
+    if (( ${#values} )); then
+        compadd -a values
+        return
+    fi
+    _aze "$@"
+}
+
+if [ "$funcstack[1]" = "_aze" ]; then
+    _aze_with_values "$@"
+else
+    compdef _aze_with_values aze
+fi
+"#;
+
+const FISH_VALUES: &str = r#"
+complete -c aze -f -a '(aze __complete -- (commandline -opc) (commandline -ct) 2>/dev/null)'
+"#;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Print the completion script for the given shell.",
+    after_help = "The scripts also complete existing projects, +tags and frame ids.\n\nExample:\n\n$ aze completions bash > ~/.local/share/bash-completion/completions/aze\n$ source <(aze completions zsh)\n$ aze completions fish > ~/.config/fish/completions/aze.fish"
+)]
+pub struct CompletionsSubcommand {
+    #[clap(help = "The shell to print the completion script for.", value_enum)]
+    pub shell: Shell,
+}
+
+/// The command line interface without the hidden completion hook, which the
+/// generators would otherwise offer as a subcommand.
+fn completion_command() -> clap::Command<'static> {
+    let cli = crate::Cli::command();
+
+    clap::Command::new("aze")
+        .args(
+            cli.get_arguments()
+                .filter(|arg| !["help", "version"].contains(&arg.get_id()))
+                .cloned(),
+        )
+        .subcommands(
+            cli.get_subcommands()
+                .filter(|subcommand| !subcommand.is_hide_set())
+                .cloned(),
+        )
+}
+
+impl MyCommand for CompletionsSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let mut command = completion_command();
+        let name = "aze";
+        let mut script: Vec<u8> = vec![];
+
+        match self.shell {
+            Shell::Bash => {
+                clap_complete::generate(Bash, &mut command, name, &mut script);
+                script.extend_from_slice(BASH_VALUES.as_bytes());
+            }
+            Shell::Zsh => {
+                clap_complete::generate(Zsh, &mut command, name, &mut script);
+                // The generated script ends by calling `_aze`, which only
+                // works when it's autoloaded as a completion function.
+                let call = b"_aze \"$@\"\n";
+                if script.ends_with(call) {
+                    script.truncate(script.len() - call.len());
+                }
+                script.extend_from_slice(ZSH_VALUES.as_bytes());
+            }
+            Shell::Fish => {
+                clap_complete::generate(Fish, &mut command, name, &mut script);
+                script.extend_from_slice(FISH_VALUES.as_bytes());
+            }
+        }
+
+        output.out.write_all(&script)?;
+
+        Ok(())
+    }
+}
+
+/// Hook called by the completion scripts with the words of the command line,
+/// the last one being the word to complete.
+#[derive(clap::Args, Debug)]
+pub struct CompleteSubcommand {
+    #[clap(raw = true)]
+    pub words: Vec<String>,
+}
+
+impl CompleteSubcommand {
+    fn values(&self) -> Result<Vec<String>> {
+        let (current, words) = match self.words.split_last() {
+            Some((current, words)) => (current.as_str(), words.get(1..).unwrap_or_default()),
+            None => return Ok(vec![]),
+        };
+
+        let command = crate::Cli::command();
+        let mut subcommand: Option<&str> = None;
+        let mut positionals = 0;
+        let mut takes_value = false;
+
+        for word in words {
+            if takes_value {
+                takes_value = false;
+                continue;
+            }
+
+            match subcommand {
+                None if word == "--profile" => takes_value = true,
+                None if !word.starts_with('-') => subcommand = Some(word),
+                None => {}
+                Some(name) if word.starts_with('-') => {
+                    takes_value = command
+                        .find_subcommand(name)
+                        .is_some_and(|command| option_takes_value(command, word));
+                }
+                Some(_) if word.starts_with('+') => {}
+                Some(_) => positionals += 1,
+            }
+        }
+
+        if let Some(profile) = words
+            .iter()
+            .position(|word| word == "--profile")
+            .and_then(|index| words.get(index + 1))
+        {
+            env::set_var("AZE_PROFILE", profile);
+        }
+
+        let previous = words.last().map(|word| word.as_str());
+        let values = match (subcommand, previous) {
+            (None, _) => vec![],
+            (Some(_), Some("-p" | "--project" | "--ignore-project")) => find_all_projects()?,
+            (Some(_), Some("-T" | "--tag" | "--ignore-tag")) => find_all_tags()?,
+            (Some(_), _) if takes_value || current.starts_with('-') => vec![],
+            (Some("start" | "add" | "pomodoro"), _) if current.starts_with('+') => find_all_tags()?
+                .into_iter()
+                .map(|tag| format!("+{}", tag))
+                .collect(),
+            (Some("start" | "add" | "pomodoro"), _) if positionals == 0 => find_all_projects()?,
+            (Some("edit" | "remove" | "split" | "join" | "shift"), _) => find_all_frames()?
+                .into_iter()
+                .map(|frame| frame.id[..7].to_string())
+                .collect(),
+            (Some("stop"), _) if positionals == 0 => running_frames()?
+                .into_iter()
+                .flat_map(|frame| [frame.project.to_owned(), frame.id[..7].to_string()])
+                .collect(),
+            _ => vec![],
+        };
+
+        let mut matching: Vec<String> = vec![];
+        for value in values {
+            if value.starts_with(current) && !matching.contains(&value) {
+                matching.push(value);
+            }
+        }
+
+        Ok(matching)
+    }
+}
+
+fn option_takes_value(command: &clap::Command, word: &str) -> bool {
+    if word.contains('=') {
+        return false;
+    }
+
+    command.get_arguments().any(|arg| {
+        let matches = match word.strip_prefix("--") {
+            Some(long) => arg.get_long() == Some(long),
+            None => word.len() == 2 && word.ends_with(arg.get_short().unwrap_or('\0')),
+        };
+
+        matches && arg.is_takes_value_set()
+    })
+}
+
+impl MyCommand for CompleteSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        for value in self.values()? {
+            writeln!(output.out, "{}", value)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use aze::cli::parse_duration;
+use serde_json::Value;
+
+use super::edit::edit_until_valid;
+use super::log::default_range_start;
+use super::max_frame_duration;
+use super::MyCommand;
+use crate::config::{config_path, read_config, AppConfig};
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ConfigAction {
+    #[clap(about = "Print the value of a setting.")]
+    Get {
+        #[clap(help = "Name of the setting, e.g. datetime_format.")]
+        key: String,
+    },
+    #[clap(about = "Change the value of a setting. Lists are given comma separated.")]
+    Set {
+        #[clap(help = "Name of the setting, e.g. datetime_format.")]
+        key: String,
+        #[clap(help = "New value of the setting.")]
+        value: String,
+    },
+    #[clap(about = "Print all settings.")]
+    List,
+    #[clap(about = "Open the configuration file in the editor.")]
+    Edit,
+    #[clap(about = "Print the path of the configuration file.")]
+    Path,
+}
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "View and change the configuration.",
+    after_help = "Example:\n\n$ aze config set working_days Mon,Tue,Wed\n$ aze config get working_days\nMon, Tue, Wed"
+)]
+pub struct ConfigSubcommand {
+    #[clap(subcommand)]
+    pub action: ConfigAction,
+}
+
+fn validate(config: &AppConfig) -> Result<()> {
+    config.validate()?;
+    max_frame_duration(config)?;
+    default_range_start(config)?;
+
+    for (key, value) in [
+        ("pomodoro_work", &config.pomodoro_work),
+        ("pomodoro_break", &config.pomodoro_break),
+    ] {
+        parse_duration(value).map_err(|error| anyhow!("Invalid {}: {}", key, error))?;
+    }
+
+    Ok(())
+}
+
+fn unknown_key(key: &str) -> anyhow::Error {
+    anyhow!(
+        "Unknown setting '{}'. Run 'aze config list' to see all settings.",
+        key
+    )
+}
+
+fn convert_value(key: &str, current: &Value, value: &str) -> Result<Value> {
+    let invalid = |expected: &str| {
+        anyhow!(
+            "Invalid value '{}' for {}, expected {}",
+            value,
+            key,
+            expected
+        )
+    };
+
+    Ok(match current {
+        Value::Bool(_) => Value::Bool(value.parse().map_err(|_| invalid("true or false"))?),
+        Value::Number(_) => Value::Number(
+            value
+                .parse::<u32>()
+                .map_err(|_| invalid("a number"))?
+                .into(),
+        ),
+        Value::Array(_) => Value::Array(
+            value
+                .split(',')
+                .map(|item| item.trim())
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        ),
+        _ if value.is_empty() => Value::Null,
+        _ => Value::String(value.to_string()),
+    })
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::String(text) => text.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+impl MyCommand for ConfigSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let config_path = config_path()?;
+        // The configuration isn't validated here, so that invalid values can
+        // be repaired.
+        let config = match read_config() {
+            Ok(config) => config,
+            Err(_) if matches!(self.action, ConfigAction::Edit | ConfigAction::Path) => {
+                AppConfig::default()
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let mut values = serde_json::to_value(&config)?;
+
+        match &self.action {
+            ConfigAction::Get { key } => {
+                let value = values.get(key).ok_or_else(|| unknown_key(key))?;
+                writeln!(output.out, "{}", display_value(value))?;
+            }
+            ConfigAction::Set { key, value } => {
+                let current = values.get(key).ok_or_else(|| unknown_key(key))?;
+                values[key] = convert_value(key, current, value)?;
+
+                let updated: AppConfig = serde_json::from_value(values)?;
+                validate(&updated)?;
+                confy::store_path(&config_path, &updated)?;
+
+                writeln!(
+                    output.out,
+                    "Set {} to {}",
+                    key,
+                    display_value(&serde_json::to_value(&updated)?[key])
+                )?;
+            }
+            ConfigAction::List => {
+                write!(output.out, "{}", toml::to_string(&config)?)?;
+            }
+            ConfigAction::Edit => {
+                let content = match std::fs::read_to_string(&config_path) {
+                    Ok(content) if !content.trim().is_empty() => content,
+                    _ => toml::to_string(&config)?,
+                };
+                let updated = edit_until_valid(content, |document| {
+                    let updated: AppConfig = toml::from_str(document)?;
+                    validate(&updated)?;
+                    Ok(updated)
+                })?;
+                confy::store_path(&config_path, &updated)?;
+
+                writeln!(output.out, "Saved {}", config_path.display())?;
+            }
+            ConfigAction::Path => {
+                writeln!(output.out, "{}", config_path.display())?;
+            }
+        }
+
+        Ok(())
+    }
+}
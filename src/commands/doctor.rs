@@ -0,0 +1,107 @@
+use anyhow::Context;
+use anyhow::Result;
+use aze::service::doctor::{diagnose, load_raw_frames};
+use colored::Colorize;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::Select;
+
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Check the stored frames for inconsistencies and optionally repair them.",
+    after_help = "Detected problems are overlapping frames, several running frames, frames ending before they start, invalid tags and missing update times. With 'allow_concurrent_frames', only overlapping or running frames of the same project are reported."
+)]
+pub struct DoctorSubcommand {
+    #[clap(
+        long = "fix",
+        display_order = 1,
+        conflicts_with = "interactive",
+        help = "Apply the suggested fix for every problem without asking."
+    )]
+    pub fix: bool,
+
+    #[clap(
+        short = 'i',
+        long = "interactive",
+        display_order = 2,
+        help = "Choose how to repair each problem."
+    )]
+    pub interactive: bool,
+}
+
+impl MyCommand for DoctorSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let config = self.config()?;
+        let problems = diagnose(
+            &load_raw_frames()?,
+            config.allow_concurrent_frames,
+            &config.datetime_format,
+        );
+
+        if problems.is_empty() {
+            writeln!(output.out, "No problems found.")?;
+            return Ok(());
+        }
+
+        let mut fixed = 0;
+        for problem in &problems {
+            writeln!(output.out, "{} {}", "Problem:".red(), problem.description)?;
+
+            let fix = if self.fix {
+                problem.fixes.first()
+            } else if self.interactive {
+                let mut items: Vec<String> = problem
+                    .fixes
+                    .iter()
+                    .map(|fix| fix.description(&config.datetime_format))
+                    .collect();
+                items.push("Skip".to_string());
+
+                let selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt("How should this be repaired?")
+                    .items(&items)
+                    .default(0)
+                    .interact()?;
+                problem.fixes.get(selection)
+            } else {
+                if let Some(fix) = problem.fixes.first() {
+                    writeln!(
+                        output.out,
+                        "\tSuggested fix: {}",
+                        fix.description(&config.datetime_format)
+                    )?;
+                }
+                None
+            };
+
+            if let Some(fix) = fix {
+                fix.apply().context("Could not repair problem")?;
+                writeln!(
+                    output.out,
+                    "\t{} {}",
+                    "Fixed:".green(),
+                    fix.description(&config.datetime_format)
+                )?;
+                fixed += 1;
+            }
+        }
+
+        if self.fix || self.interactive {
+            writeln!(
+                output.out,
+                "Fixed {} of {} problem(s).",
+                fixed,
+                problems.len()
+            )?;
+        } else {
+            writeln!(
+                output.out,
+                "Found {} problem(s). Run with --fix or --interactive to repair them.",
+                problems.len()
+            )?;
+        }
+
+        Ok(())
+    }
+}
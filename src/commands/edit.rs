@@ -1,30 +1,58 @@
+use crate::diesel::Connection;
 use crate::diesel::ExpressionMethods;
 use crate::diesel::RunQueryDsl;
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
-use chrono::NaiveDateTime;
-use colored::Colorize;
+use aze::cli::parse_to_datetime;
+use aze::cli::process_project;
+use aze::cli::process_tags;
 use aze::database::establish_connection;
 use aze::database::MyJsonType;
+use aze::error::Error;
 use aze::display::frame::JsonFrame;
+use aze::display::frame::JsonFrameWithId;
 use aze::models::Frame;
 use aze::schema::frames;
+use aze::service::frame::find_between;
 use aze::service::frame::find_frame_by_short;
-use aze::service::frame::frame_collides;
-use aze::service::frame::frame_start_collides;
 use aze::service::frame::last_created_frame;
-use aze::service::frame::last_started_frame;
+use aze::service::frame::overlapping_frames;
+use aze::service::frame::ranges_overlap;
+use aze::service::project::with_descendants;
+use chrono::{Local, NaiveDateTime};
+use colored::Colorize;
+use std::io::Write;
 
 use super::MyCommand;
 
 #[derive(clap::Args, Debug)]
-#[clap(about = "Edit a frame.")]
+#[clap(
+    about = "Edit a frame.",
+    after_help = "Several frames can be edited at once by selecting them with --from, --to or --project:\n\n$ aze edit --from 2018-03-20 --to 2018-03-21 --project apollo11"
+)]
 pub struct EditSubcommand {
     #[clap(
-        help = "Frame ID which should be edited. If not specified, will edit last frame recorded."
+        help = "Frame ID which should be edited. If not specified, will edit last frame recorded.",
+        conflicts_with_all = &["from", "to", "projects"]
     )]
     pub frame_id: Option<String>,
 
+    #[clap(help = "Edit all frames starting at or after this date.", display_order = 1, short = 'f', long = "from", value_parser = parse_to_datetime)]
+    pub from: Option<NaiveDateTime>,
+
+    #[clap(help = "Edit all frames starting at or before this date.", display_order = 2, short = 't', long = "to", value_parser = parse_to_datetime)]
+    pub to: Option<NaiveDateTime>,
+
+    #[clap(
+        short = 'p',
+        long = "project",
+        help = "Edit all frames of the given project and its sub-projects. You can add other projects by using this option several times.",
+        multiple = true,
+        display_order = 5
+    )]
+    pub projects: Vec<String>,
+
     #[clap(
         help = "Confirm addition of new project",
         display_order = 3,
@@ -42,68 +70,329 @@ pub struct EditSubcommand {
     pub confirm_tags: bool,
 }
 
+impl EditSubcommand {
+    fn is_bulk(&self) -> bool {
+        self.from.is_some() || self.to.is_some() || !self.projects.is_empty()
+    }
+
+    fn run_bulk(&self, output: super::Output) -> Result<()> {
+        let projects = if self.projects.is_empty() {
+            vec![]
+        } else {
+            with_descendants(&self.projects)?
+        };
+        let selected = find_between(self.from.as_ref(), self.to.as_ref(), &projects)?;
+        if selected.is_empty() {
+            return Err(anyhow!("No frames found for the given filters"));
+        }
+
+        let documents: Vec<JsonFrameWithId> = selected.iter().map(JsonFrameWithId::new).collect();
+        let content = serde_json::ser::to_string_pretty(&documents)?;
+        let selected_ids: Vec<String> = selected.iter().map(|frame| frame.id.to_string()).collect();
+
+        let changes = edit_until_valid(content, |document| {
+            let edited_frames: Vec<JsonFrameWithId> = serde_json::from_str(document)
+                .map_err(|error| anyhow!("Error while parsing inputted values: {}", error))?;
+
+            let mut changes: Vec<(&Frame, JsonFrame)> = vec![];
+            for edited_frame in edited_frames {
+                let frame = selected
+                    .iter()
+                    .find(|frame| frame.id == edited_frame.id)
+                    .ok_or_else(|| anyhow!("Unknown frame id {}", edited_frame.id))?;
+                if changes.iter().any(|(changed, _)| changed.id == frame.id) {
+                    return Err(anyhow!("Frame {} is listed more than once", &frame.id[..7]));
+                }
+                if JsonFrame::new(frame) != edited_frame.frame {
+                    changes.push((frame, edited_frame.frame));
+                }
+            }
+
+            validate_bulk(&selected, &changes, &selected_ids)?;
+            Ok(changes)
+        })?;
+
+        if changes.is_empty() {
+            writeln!(output.out, "No changes.")?;
+            return Ok(());
+        }
+
+        for (_, new_frame) in &changes {
+            self.confirm_new(new_frame)?;
+        }
+
+        let datetime_format = self.config()?.datetime_format;
+        for (frame, new_frame) in &changes {
+            write_diff(output.out, frame, new_frame, &datetime_format)?;
+        }
+
+        let mut conn = establish_connection()?;
+        conn.transaction::<_, diesel::result::Error, _>(|conn| {
+            for (frame, new_frame) in &changes {
+                diesel::update(*frame)
+                    .set((
+                        frames::start.eq(new_frame.start),
+                        frames::end.eq(new_frame.end),
+                        frames::project.eq(&new_frame.project),
+                        frames::tags.eq(MyJsonType(serde_json::json!(new_frame.tags))),
+                        frames::note.eq(&new_frame.note),
+                    ))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+        .map_err(Error::Query)
+        .context("Could not save edited frames")?;
+
+        writeln!(output.out, "Updated {} frame(s).", changes.len())?;
+        Ok(())
+    }
+
+    fn confirm_new(&self, frame: &JsonFrame) -> Result<()> {
+        if !process_project(frame.project.to_string(), self.confirm_project)?
+            || !process_tags(frame.tags.to_owned(), self.confirm_tags)?
+        {
+            return Err(anyhow!("Aborted!"));
+        }
+
+        Ok(())
+    }
+}
+
+pub fn edit_until_valid<T>(content: String, parse: impl Fn(&str) -> Result<T>) -> Result<T> {
+    let mut content = content;
+
+    loop {
+        let edited = edit::edit(&content)?;
+        let document = edited
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        if document.trim().is_empty() {
+            return Err(anyhow!("Aborted!"));
+        }
+
+        match parse(&document) {
+            Ok(value) => return Ok(value),
+            Err(error) if edited == content => return Err(error),
+            Err(error) => {
+                content = format!(
+                    "# {}\n# Fix the error and save again. Save without changes or clear the file to abort.\n{}",
+                    error, document
+                );
+            }
+        }
+    }
+}
+
+fn validate_frame(frame_id: &str, frame: &JsonFrame) -> Result<()> {
+    let now = Local::now().naive_local();
+
+    if frame.start > now {
+        return Err(anyhow!(
+            "Frame {}: start must not be in the future",
+            &frame_id[..7]
+        ));
+    }
+
+    if let Some(end) = frame.end {
+        if end <= frame.start {
+            return Err(anyhow!("Frame {}: end must be after start", &frame_id[..7]));
+        }
+        if end > now {
+            return Err(anyhow!(
+                "Frame {}: end must not be in the future",
+                &frame_id[..7]
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_bulk(
+    selected: &[Frame],
+    changes: &[(&Frame, JsonFrame)],
+    selected_ids: &[String],
+) -> Result<()> {
+    let result: Vec<(&String, JsonFrame, bool)> = selected
+        .iter()
+        .map(
+            |frame| match changes.iter().find(|(changed, _)| changed.id == frame.id) {
+                Some((_, new_frame)) => (&frame.id, new_frame.clone(), true),
+                None => (&frame.id, JsonFrame::new(frame), false),
+            },
+        )
+        .collect();
+
+    for (index, (frame_id, frame, changed)) in result.iter().enumerate() {
+        if *changed {
+            validate_frame(frame_id, frame)?;
+        }
+
+        for (other_id, other, other_changed) in &result[index + 1..] {
+            if (*changed || *other_changed)
+                && ranges_overlap(
+                    &frame.start,
+                    frame.end.as_ref(),
+                    &other.start,
+                    other.end.as_ref(),
+                )
+            {
+                return Err(anyhow!(
+                    "Frame {} overlaps with frame {}",
+                    &frame_id[..7],
+                    &other_id[..7]
+                ));
+            }
+        }
+
+        if !changed {
+            continue;
+        }
+
+        if let Some(other) =
+            overlapping_frames(&frame.start, frame.end.as_ref(), selected_ids)?.first()
+        {
+            return Err(anyhow!(
+                "Frame {} overlaps with frame {}",
+                &frame_id[..7],
+                &other.id[..7]
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn write_diff(
+    out: &mut dyn Write,
+    frame: &Frame,
+    new_frame: &JsonFrame,
+    datetime_format: &str,
+) -> Result<()> {
+    let old_frame = JsonFrame::new(frame);
+    let format = |value: Option<NaiveDateTime>| {
+        value.map_or("-".to_string(), |value| {
+            value.format(datetime_format).to_string()
+        })
+    };
+
+    writeln!(out, "{}", &frame.id[..7].bright_black())?;
+    if old_frame.start != new_frame.start {
+        writeln!(
+            out,
+            "\tstart\t{} -> {}",
+            format(Some(old_frame.start)).red(),
+            format(Some(new_frame.start)).green()
+        )?;
+    }
+    if old_frame.end != new_frame.end {
+        writeln!(
+            out,
+            "\tend\t{} -> {}",
+            format(old_frame.end).red(),
+            format(new_frame.end).green()
+        )?;
+    }
+    if old_frame.project != new_frame.project {
+        writeln!(
+            out,
+            "\tproject\t{} -> {}",
+            old_frame.project.red(),
+            new_frame.project.green()
+        )?;
+    }
+    if old_frame.tags != new_frame.tags {
+        writeln!(
+            out,
+            "\ttags\t[{}] -> [{}]",
+            old_frame.tags.join(", ").red(),
+            new_frame.tags.join(", ").green()
+        )?;
+    }
+    if old_frame.note != new_frame.note {
+        writeln!(
+            out,
+            "\tnote\t{} -> {}",
+            old_frame.note.unwrap_or_default().red(),
+            new_frame.note.to_owned().unwrap_or_default().green()
+        )?;
+    }
+
+    Ok(())
+}
+
 impl MyCommand for EditSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
-        let frame: Frame;
+        if self.is_bulk() {
+            return self.run_bulk(output);
+        }
 
-        if self.frame_id.is_some() {
-            let frame_id = self.frame_id.as_ref().unwrap().to_string();
-            let frame_by_id = find_frame_by_short(&frame_id);
-            if frame_by_id.is_err() {
-                return Err(anyhow!("No frame found with id {}", frame_id));
-            }
-            frame = frame_by_id.unwrap();
+        let frame: Frame = if let Some(frame_id) = &self.frame_id {
+            find_frame_by_short(frame_id)?
         } else {
-            let last_frame = last_created_frame();
+            let last_frame = last_created_frame()?;
             if last_frame.is_none() {
                 return Err(anyhow!(
                     "No frames recorded yet. It's time to create your first one!"
                 ));
             }
-            frame = last_frame.unwrap();
-        }
+            last_frame.unwrap()
+        };
 
         let json_frame = JsonFrame::new(&frame);
 
-        let content = serde_json::ser::to_string(&json_frame).expect("Could not serialize frame");
-        let edited = edit::edit(content)?;
+        let content = serde_json::ser::to_string(&json_frame)?;
 
-        let result_frame: serde_json::Result<JsonFrame> = serde_json::from_str(edited.as_str());
+        let new_frame = edit_until_valid(content, |document| {
+            let new_frame: JsonFrame = serde_json::from_str(document)
+                .map_err(|error| anyhow!("Error while parsing inputted values: {}", error))?;
 
-        if result_frame.is_err() {
-            return Err(anyhow!(
-                "Error while parsing inputted values: {}",
-                result_frame.unwrap_err().to_string()
-            ));
-        }
+            if new_frame == json_frame {
+                return Ok(new_frame);
+            }
 
-        let new_frame = result_frame.unwrap();
+            validate_frame(&frame.id, &new_frame)?;
 
-        if new_frame.end.is_none() {
-            let current_frame = last_started_frame();
-            if current_frame.is_some() && frame.end.is_some() {
-                return Err(anyhow!("Frame already started"));
+            let others = overlapping_frames(
+                &new_frame.start,
+                new_frame.end.as_ref(),
+                &[frame.id.to_string()],
+            )?;
+            if let Some(other) = others.first() {
+                return Err(anyhow!(
+                    "Frame {} overlaps with frame {}",
+                    &frame.id[..7],
+                    &other.id[..7]
+                ));
             }
 
-            if frame_start_collides(&new_frame.start) {
-                return Err(anyhow!("Frame start collides"));
-            }
+            Ok(new_frame)
+        })?;
+
+        if new_frame == json_frame {
+            return Ok(());
         }
 
+        self.confirm_new(&new_frame)?;
 
         let update_satement = diesel::update(&frame).set((
             frames::start.eq(new_frame.start),
             frames::end.eq(new_frame.end),
             frames::project.eq(new_frame.project),
             frames::tags.eq(MyJsonType(serde_json::json!(new_frame.tags))),
+            frames::note.eq(new_frame.note),
         ));
 
-        let mut conn = establish_connection();
-        let result = update_satement.execute(&mut conn);
+        let mut conn = establish_connection()?;
+        update_satement
+            .execute(&mut conn)
+            .map_err(Error::Query)
+            .with_context(|| format!("Could not save frame with id {}", frame.id))?;
 
-        if result.is_err() {
-            return Err(anyhow!("Could not save frame with id {}", frame.id));
-        }
         Ok(())
     }
 }
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use aze::cli::convert_tags;
+use aze::service::frame::create_frame;
+use colored::Colorize;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::Input;
+
+use super::gaps::GapRange;
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Walk through untracked time within the working hours and assign it to projects.",
+    after_help = "For every gap, enter a project followed by optional tags (e.g. 'apollo11 +brakes'). Leave the input empty to skip the gap.\n\nIf a project is given, every gap is assigned to it without asking:\n\n$ aze fill apollo11 +brakes --from 2018-03-20 --to 2018-03-21"
+)]
+pub struct FillSubcommand {
+    #[clap(help = "Name of the project which should be assigned to every gap without asking.")]
+    pub project: Option<String>,
+
+    #[clap(help = "Tag(s) which should be added to every gap. Each tag has to be prepended with a plus sign.", value_parser = convert_tags, requires = "project")]
+    pub tags: Vec<String>,
+
+    #[clap(flatten)]
+    pub range: GapRange,
+}
+
+/// Splits an answer like `apollo11 +brakes` into the project and its tags,
+/// `None` if the answer is empty.
+fn parse_answer(answer: &str) -> Result<Option<(String, Vec<String>)>, String> {
+    let mut parts = answer.split_whitespace();
+    let project = match parts.next() {
+        Some(project) => project.to_string(),
+        None => return Ok(None),
+    };
+    let tags = parts
+        .map(|tag| {
+            convert_tags(tag).map_err(|_| {
+                format!(
+                    "Invalid tag '{}', each tag has to be prepended with a plus sign",
+                    tag
+                )
+            })
+        })
+        .collect::<Result<Vec<String>, String>>()?;
+
+    Ok(Some((project, tags)))
+}
+
+impl MyCommand for FillSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let config = self.config()?;
+        let mut filled = 0;
+
+        for (start, end) in self.range.gaps(&config)? {
+            if let Some(project) = &self.project {
+                create_frame(&start, &end, project, self.tags.to_owned(), None)?;
+                filled += 1;
+
+                writeln!(
+                    output.out,
+                    "Added project {} from {} to {}",
+                    project.purple(),
+                    start.format(&config.datetime_format).to_string().green(),
+                    end.format(&config.datetime_format).to_string().green()
+                )?;
+                continue;
+            }
+
+            let answer: String = Input::with_theme(&ColorfulTheme::default())
+                .with_prompt(format!(
+                    "{} to {}",
+                    start.format(&config.datetime_format),
+                    end.format(&config.time_format)
+                ))
+                .allow_empty(true)
+                .validate_with(|answer: &String| parse_answer(answer).map(|_| ()))
+                .interact_text()?;
+
+            let (project, tags) = match parse_answer(&answer).map_err(anyhow::Error::msg)? {
+                Some(answer) => answer,
+                None => continue,
+            };
+
+            create_frame(&start, &end, &project, tags, None)?;
+            filled += 1;
+
+            writeln!(
+                output.out,
+                "Added project {} from {} to {}",
+                project.purple(),
+                start.format(&config.datetime_format).to_string().green(),
+                end.format(&config.datetime_format).to_string().green()
+            )?;
+        }
+
+        writeln!(output.out, "Filled {} gap(s).", filled)?;
+
+        Ok(())
+    }
+}
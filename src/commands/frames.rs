@@ -14,7 +14,7 @@ pub struct FramesSubcommand {
 
 impl MyCommand for FramesSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
-        let frames = find_all();
+        let frames = find_all()?;
 
         for frame in frames {
             writeln!(
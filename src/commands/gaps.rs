@@ -0,0 +1,85 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use aze::cli::parse_duration;
+use aze::cli::parse_to_datetime;
+use aze::format_duration_with;
+use aze::service::gap::find_gaps;
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use colored::Colorize;
+
+use super::MyCommand;
+use crate::config::AppConfig;
+
+#[derive(clap::Args, Debug)]
+pub struct GapRange {
+    #[clap(help = "The date from when gaps should be searched. Defaults to seven days ago.", display_order = 1, short = 'f', long = "from", value_parser = parse_to_datetime)]
+    pub from: Option<NaiveDateTime>,
+
+    #[clap(help = "The date until gaps should be searched. Defaults to now.", display_order = 2, short = 't', long = "to", value_parser = parse_to_datetime)]
+    pub to: Option<NaiveDateTime>,
+
+    #[clap(help = "Ignore gaps shorter than this duration, e.g. 15m.", display_order = 3, short = 'm', long = "min", value_parser = parse_duration, default_value = "5m")]
+    pub min: Duration,
+}
+
+impl GapRange {
+    pub fn gaps(&self, config: &AppConfig) -> Result<Vec<(NaiveDateTime, NaiveDateTime)>> {
+        let (day_start, day_end) = config.working_hours()?;
+        let days = config.working_days()?;
+
+        let now = Local::now().naive_local();
+        let last_week = (now - Duration::weeks(1))
+            .date()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let from = self.from.unwrap_or(last_week);
+        let to = self.to.unwrap_or(now);
+
+        if from > to {
+            return Err(anyhow!("'from' must be anterior to 'to'"));
+        }
+
+        Ok(find_gaps(
+            &from, &to, &day_start, &day_end, &days, &self.min,
+        )?)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Display untracked time within the configured working hours.",
+    after_help = "Working hours are configured with 'working_hours_start', 'working_hours_end' and 'working_days'.\n\nExample:\n\n$ aze gaps --from 2018-03-19\nMon 19 3 2018\n\t11:30 to 13:00\t1h 30m 00s"
+)]
+pub struct GapsSubcommand {
+    #[clap(flatten)]
+    pub range: GapRange,
+}
+
+impl MyCommand for GapsSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let mut actual_day: Option<NaiveDate> = None;
+
+        let config = self.config()?;
+
+        for (start, end) in self.range.gaps(&config)? {
+            if actual_day != Some(start.date()) {
+                writeln!(
+                    output.out,
+                    "{}",
+                    start.format(&config.date_format).to_string().cyan()
+                )?;
+                actual_day = Some(start.date());
+            }
+
+            writeln!(
+                output.out,
+                "\t{} to {}\t{}",
+                start.format(&config.time_format).to_string().green(),
+                end.format(&config.time_format).to_string().green(),
+                format_duration_with(&(end - start), &config.duration_format)
+            )?;
+        }
+
+        Ok(())
+    }
+}
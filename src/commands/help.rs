@@ -0,0 +1,196 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use clap::CommandFactory;
+use colored::Colorize;
+
+use super::MyCommand;
+
+pub struct Topic {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub about: &'static str,
+    pub text: &'static str,
+}
+
+pub const TOPICS: [Topic; 4] = [
+    Topic {
+        name: "dates",
+        title: "DATE FORMATS",
+        about: "How dates, times and durations are entered and displayed.",
+        text: "\
+Dates given to options like --from, --to and --at are parsed with the
+configured 'datetime_format', which is \"%Y-%m-%d %H:%M\" by default. A date
+without a time means midnight of that day.
+
+$ aze log --from 2018-03-20 --to \"2018-03-21 12:00\"
+$ aze start apollo11 --at \"2018-03-20 09:15\"
+
+The formats use the strftime syntax of chrono, e.g. %Y for the year, %m for
+the month, %d for the day, %H for the hour and %M for the minute. The
+'datetime_format' has to contain a full date and time:
+
+$ aze config set datetime_format \"%d.%m.%Y %H:%M\"
+
+How days, times and durations are displayed is configured with
+'date_format', 'time_format' and 'duration_format'. Durations support %H, %M,
+%S and %%.
+
+Durations given to options like --min, --work or --by are written as numbers
+with the units d, h, m and s, e.g. 90m or 1h30m. 'aze shift' accepts a leading
++ or - sign to move frames forward or back.",
+    },
+    Topic {
+        name: "tags",
+        title: "TAGS",
+        about: "How tags are added to frames and used in filters.",
+        text: "\
+Tags are given after the project, each of them prepended with a plus sign.
+They are stored without the plus sign.
+
+$ aze start apollo11 +launch +review
+$ aze add hubble +mirror --from 2018-03-20 --to \"2018-03-20 13:00\"
+
+When only tags are given to 'aze start', the project is taken from the
+nearest .aze.toml, which can also hold default tags for its directory.
+
+With --confirm-new-tags, 'aze start', 'aze add' and 'aze edit' ask before
+using a tag which wasn't used before. 'aze log' shows only frames with the
+given tags with --tag and hides them with --ignore-tag.",
+    },
+    Topic {
+        name: "filters",
+        title: "FILTERS",
+        about: "Options to select the frames shown by log and search.",
+        text: "\
+'aze log' and 'aze search' show the frames of the configured 'log_range',
+the last seven days by default. The range is changed with:
+
+  -d, --day, -w, --week, -m, --month, -y, --year
+      frames of the current day, week, month or year
+  -a, --all
+      all frames
+  -f, --from DATE, -t, --to DATE
+      frames between the given dates
+  -c, --current
+      include the running frame
+
+'aze log' selects frames further with:
+
+  -p, --project PROJECT, --ignore-project PROJECT
+      only or all but the given project and its sub-projects
+  -T, --tag TAG, --ignore-tag TAG
+      only or all but the frames with the given tag
+  --grep TEXT
+      only frames whose note contains the text
+
+Projects are nested by separating them with a slash, so -p nasa also selects
+nasa/apollo11. All of these options can be given several times.
+
+$ aze log --week -p nasa --ignore-tag review",
+    },
+    Topic {
+        name: "config",
+        title: "CONFIGURATION",
+        about: "The settings in the configuration file and their defaults.",
+        text: "\
+The configuration file is printed by 'aze config path' and changed with
+'aze config set KEY VALUE' or 'aze config edit'. Each profile has its own
+configuration, see 'aze profile'.
+
+  data_dir
+      directory of the frames database
+  datetime_format
+      format to enter and show dates with time, \"%Y-%m-%d %H:%M\"
+  date_format
+      format of days in 'aze log', \"%a %-d %-m %Y\"
+  time_format
+      format of times in 'aze log', \"%H:%M\"
+  duration_format
+      format of durations, \"%Hh %Mm %Ss\"
+  week_start
+      first day of the week used by --week, \"Mon\"
+  log_range
+      default range of 'aze log': day, week, month, year, all or a duration, \"7d\"
+  log_current
+      include the running frame in 'aze log', false
+  log_pager
+      show 'aze log' through a pager when it doesn't fit, false
+  color
+      color output: auto, always or never, \"auto\"
+  working_hours_start, working_hours_end
+      working hours used by 'aze gaps', \"09:00\" to \"17:00\"
+  working_days
+      working days used by 'aze gaps', Mon to Fri
+  allow_concurrent_frames
+      allow several frames to run at the same time, false
+  stop_on_start
+      stop the running frame when a new one is started, false
+  max_frame_duration
+      offer to stop frames running longer than this duration, unset
+  pomodoro_work, pomodoro_break, pomodoro_cycles
+      defaults of 'aze pomodoro', \"25m\", \"5m\" and 4
+
+$ aze config set log_range week",
+    },
+];
+
+pub fn find_topic(name: &str) -> Option<&'static Topic> {
+    TOPICS.iter().find(|topic| topic.name == name)
+}
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Print help for a subcommand or one of the help topics.",
+    after_help = "Example:\n\n$ aze help start\n$ aze help dates"
+)]
+pub struct HelpSubcommand {
+    #[clap(help = "Name of a subcommand or help topic.")]
+    pub topic: Option<String>,
+}
+
+impl MyCommand for HelpSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let mut command = crate::Cli::command();
+
+        match &self.topic {
+            None => {
+                let mut help: Vec<u8> = vec![];
+                command.write_long_help(&mut help)?;
+                output.out.write_all(&help)?;
+                writeln!(output.out, "\n\n{}", "TOPICS:".yellow())?;
+                for topic in &TOPICS {
+                    writeln!(
+                        output.out,
+                        "    {}{}",
+                        format!("{:<12}", topic.name).green(),
+                        topic.about
+                    )?;
+                }
+                writeln!(
+                    output.out,
+                    "\nSee 'aze help <topic>' for more information on a topic."
+                )?;
+            }
+            Some(name) => {
+                if let Some(topic) = find_topic(name) {
+                    writeln!(output.out, "{}", topic.text)?;
+                } else if command
+                    .get_subcommands()
+                    .any(|subcommand| !subcommand.is_hide_set() && subcommand.get_name() == name)
+                {
+                    match command.try_get_matches_from_mut(["aze", name.as_str(), "--help"]) {
+                        Err(error) => write!(output.out, "{}", error)?,
+                        Ok(_) => unreachable!("--help always stops parsing"),
+                    }
+                } else {
+                    return Err(anyhow!(
+                        "No subcommand or help topic '{}'. Run 'aze help' to list them.",
+                        name
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use aze::service::frame::find_frame_by_short;
+use aze::service::frame::join_frames;
+use aze::service::frame::overlapping_frames;
+use colored::Colorize;
+
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Join two adjacent frames of the same project into one frame.",
+    after_help = "Example:\n\n$ aze join f1c4815 9d1a989"
+)]
+pub struct JoinSubcommand {
+    #[clap(help = "Frame id of the first frame.")]
+    pub first: String,
+
+    #[clap(help = "Frame id of the second frame.")]
+    pub second: String,
+}
+
+impl MyCommand for JoinSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let mut first = find_frame_by_short(&self.first)?;
+        let mut second = find_frame_by_short(&self.second)?;
+
+        if first.id == second.id {
+            return Err(anyhow!("A frame can't be joined with itself"));
+        }
+        if second.start < first.start {
+            std::mem::swap(&mut first, &mut second);
+        }
+
+        if first.project != second.project {
+            return Err(anyhow!(
+                "Frames {} and {} belong to different projects",
+                &first.id[..7],
+                &second.id[..7]
+            ));
+        }
+
+        let first_end = first
+            .end
+            .ok_or_else(|| anyhow!("Frame {} is still running", &first.id[..7]))?;
+        if first_end > second.start {
+            return Err(anyhow!(
+                "Frames {} and {} overlap",
+                &first.id[..7],
+                &second.id[..7]
+            ));
+        }
+
+        let between = overlapping_frames(
+            &first_end,
+            Some(&second.start),
+            &[first.id.to_string(), second.id.to_string()],
+        )?;
+        if !between.is_empty() {
+            return Err(anyhow!(
+                "Frames {} and {} are not adjacent, frame {} lies in between",
+                &first.id[..7],
+                &second.id[..7],
+                &between[0].id[..7]
+            ));
+        }
+
+        join_frames(&first, &second)
+            .with_context(|| format!("Could not join frames {} and {}", first.id, second.id))?;
+
+        writeln!(
+            output.out,
+            "Joined frames {} and {} of project {}",
+            &first.id[..7].bright_black(),
+            &second.id[..7].bright_black(),
+            first.project.purple()
+        )?;
+
+        Ok(())
+    }
+}
@@ -5,57 +5,34 @@ use chrono::Duration;
 use chrono::Local;
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use chrono::Weekday;
+use diesel::sqlite::Sqlite;
 use diesel::associations::HasTable;
 use diesel::dsl::not;
+use diesel::EscapeExpressionMethods;
 use diesel::TextExpressionMethods;
 use aze::cli::parse_to_datetime;
+use aze::cli::parse_duration;
+use aze::format_duration_with;
 use aze::database::establish_connection;
+use aze::error::Error;
 use aze::display::Display;
 use aze::models::Frame;
+use aze::schema::frames::BoxedQuery;
+use aze::service::project::with_descendants;
 
 use crate::diesel::ExpressionMethods;
 use crate::diesel::QueryDsl;
 use crate::diesel::RunQueryDsl;
 use colored::Colorize;
+use std::io::Write;
 
+use super::page;
 use super::MyCommand;
+use crate::config::AppConfig;
 
 #[derive(clap::Args, Debug)]
-pub struct LogSubcommand {
-    #[clap(
-        short = 'p',
-        long = "project",
-        help = "Logs activity only for the given project. You can add other projects by using this option several times.",
-        multiple = true,
-        display_order = 10
-    )]
-    pub projects: Vec<String>,
-
-    #[clap(
-        short = 'T',
-        long = "tag",
-        help = "Logs activity only for frames containing the given tag. You can add several tags by using this option multiple times.",
-        multiple = true,
-        display_order = 11
-    )]
-    pub tags: Vec<String>,
-
-    #[clap(
-        long = "ignore-project",
-        help = "Logs activity for all projects but the given ones. You can ignore several projects by using this option several times.",
-        multiple = true,
-        display_order = 12
-    )]
-    pub ignored_projects: Vec<String>,
-
-    #[clap(
-        long = "ignore-tag",
-        help = "Logs activity for all tags but the given ones. You can ignore several tags by using this option several times.",
-        multiple = true,
-        display_order = 13
-    )]
-    pub ignored_tags: Vec<String>,
-
+pub struct TimeRange {
     #[clap(
         short = 'c',
         long = "current",
@@ -64,15 +41,7 @@ pub struct LogSubcommand {
     )]
     pub current: bool,
 
-    #[clap(
-        short = 'r',
-        long = "reverse",
-        display_order = 2,
-        help = "Reverse the order of the days in output."
-    )]
-    pub reverse: bool,
-
-    #[clap(help = "The date from when the log should start. Defaults to seven days ago.", display_order = 3, short = 'f', long = "from", value_parser = parse_to_datetime)]
+    #[clap(help = "The date from when the log should start. Defaults to the configured 'log_range', seven days ago by default.", display_order = 3, short = 'f', long = "from", value_parser = parse_to_datetime)]
     pub from: Option<NaiveDateTime>,
     #[clap(help = "The date at which the log should stop (inclusive). Defaults to tomorrow", display_order = 4, short = 't', long = "to", value_parser = parse_to_datetime)]
     pub to: Option<NaiveDateTime>,
@@ -121,6 +90,157 @@ pub struct LogSubcommand {
         help = "Reports all activities."
     )]
     pub all: bool,
+}
+
+fn range_start(range: &str, week_start: Weekday) -> Option<NaiveDateTime> {
+    let today = Local::now().date_naive();
+
+    let date = match range {
+        "day" => today,
+        "week" => {
+            let days = (7 + today.weekday().num_days_from_monday()
+                - week_start.num_days_from_monday())
+                % 7;
+            today - Duration::days(days.into())
+        }
+        "month" => today.with_day(1).unwrap(),
+        "year" => today.with_ordinal(1).unwrap(),
+        "all" => NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+        _ => return None,
+    };
+
+    date.and_hms_opt(0, 0, 0)
+}
+
+/// Start of the configured default log range, either one of `day`, `week`,
+/// `month`, `year` and `all` or a duration like `7d`.
+pub fn default_range_start(config: &AppConfig) -> Result<NaiveDateTime> {
+    if let Some(start) = range_start(&config.log_range, config.week_start()?) {
+        return Ok(start);
+    }
+
+    let duration = parse_duration(&config.log_range).map_err(|error| {
+        anyhow!(
+            "Invalid log_range in config, expected day, week, month, year, all or a duration: {}",
+            error
+        )
+    })?;
+
+    Ok(Local::now().naive_local() - duration)
+}
+
+impl TimeRange {
+    fn short_filter_start(&self, config: &AppConfig) -> Result<Option<NaiveDateTime>> {
+        let range = if self.day {
+            "day"
+        } else if self.week {
+            "week"
+        } else if self.month {
+            "month"
+        } else if self.year {
+            "year"
+        } else if self.all {
+            "all"
+        } else {
+            return Ok(None);
+        };
+
+        Ok(range_start(range, config.week_start()?))
+    }
+
+    pub fn filter<'a>(
+        &self,
+        query: BoxedQuery<'a, Sqlite>,
+        config: &AppConfig,
+    ) -> Result<BoxedQuery<'a, Sqlite>> {
+        use aze::schema::frames::dsl::*;
+
+        let filter_from = match self.from.or(self.short_filter_start(config)?) {
+            Some(filter_from) => filter_from,
+            None => default_range_start(config)?,
+        };
+
+        let tomorrow = Local::now()
+            .date_naive()
+            .succ_opt()
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        let filter_end = self.to.unwrap_or(tomorrow);
+
+        if filter_from > filter_end {
+            return Err(anyhow!("'from' must be anterior to 'to'"));
+        }
+
+        let mut query = query
+            .filter(deleted.eq(false))
+            .filter(start.gt(filter_from));
+
+        if filter_end > Local::now().naive_local() {
+            if !self.current && !config.log_current {
+                query = query.filter(not(end.is_null()));
+            }
+        } else {
+            query = query.filter(end.lt(filter_end));
+        }
+
+        Ok(query)
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct LogSubcommand {
+    #[clap(
+        short = 'p',
+        long = "project",
+        help = "Logs activity only for the given project and its sub-projects. You can add other projects by using this option several times.",
+        multiple = true,
+        display_order = 10
+    )]
+    pub projects: Vec<String>,
+
+    #[clap(
+        short = 'T',
+        long = "tag",
+        help = "Logs activity only for frames containing the given tag. You can add several tags by using this option multiple times.",
+        multiple = true,
+        display_order = 11
+    )]
+    pub tags: Vec<String>,
+
+    #[clap(
+        long = "ignore-project",
+        help = "Logs activity for all projects but the given ones and their sub-projects. You can ignore several projects by using this option several times.",
+        multiple = true,
+        display_order = 12
+    )]
+    pub ignored_projects: Vec<String>,
+
+    #[clap(
+        long = "ignore-tag",
+        help = "Logs activity for all tags but the given ones. You can ignore several tags by using this option several times.",
+        multiple = true,
+        display_order = 13
+    )]
+    pub ignored_tags: Vec<String>,
+
+    #[clap(
+        long = "grep",
+        help = "Logs activity only for frames whose note contains the given text (case insensitive).",
+        display_order = 14
+    )]
+    pub grep: Option<String>,
+
+    #[clap(flatten)]
+    pub range: TimeRange,
+
+    #[clap(
+        short = 'r',
+        long = "reverse",
+        display_order = 2,
+        help = "Reverse the order of the days in output."
+    )]
+    pub reverse: bool,
 
     #[clap(
         short = 'j',
@@ -145,7 +265,7 @@ pub struct LogSubcommand {
         long = "pager",
         display_order = 9,
         group = "view",
-        help = "View output through a pager."
+        help = "View output through $PAGER or 'less -R' if it doesn't fit on the terminal. Enabled by default with 'log_pager' in the config."
     )]
     pub pager: bool,
 }
@@ -154,7 +274,7 @@ impl LogSubcommand {
     fn parse_project(&self) -> Vec<&String> {
         let mut difference = vec![];
         for i in &self.projects {
-            if self.ignored_projects.contains(&i) {
+            if self.ignored_projects.contains(i) {
                 difference.push(i);
             }
         }
@@ -164,7 +284,7 @@ impl LogSubcommand {
     fn parse_tags(&self) -> Vec<&String> {
         let mut difference = vec![];
         for i in &self.tags {
-            if self.ignored_tags.contains(&i) {
+            if self.ignored_tags.contains(i) {
                 difference.push(i);
             }
         }
@@ -172,15 +292,22 @@ impl LogSubcommand {
     }
 }
 
+/// Escapes the wildcards of a LIKE pattern, to be used with `.escape('\\')`.
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 impl MyCommand for LogSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
         use aze::schema::frames::dsl::*;
 
-        if self.year || self.month || self.day || self.json || self.csv || self.all {
+        if self.json || self.csv {
             return Err(anyhow!("NOT IMPLEMENTED"));
         }
 
-        let mut conn = establish_connection();
+        let mut conn = establish_connection()?;
 
         let collisions = self.parse_project();
         if !collisions.is_empty() {
@@ -192,26 +319,15 @@ impl MyCommand for LogSubcommand {
             return Err(anyhow!("given tags can't be ignored at the same time"));
         }
 
-        let last_week = (Local::now() - Duration::weeks(1)).naive_utc();
-        let filter_from = self.from.unwrap_or(last_week);
-
-        let tomorrow = NaiveDate::succ(&Local::today().naive_local()).and_hms(23, 59, 59);
-        let filter_end = self.to.unwrap_or(tomorrow);
-
-        if filter_from > filter_end {
-            return Err(anyhow!("'from' must be anterior to 'to'"));
-        }
-
-        let mut query = frames::table().into_boxed();
+        let config = self.config()?;
+        let mut query = self.range.filter(frames::table().into_boxed(), &config)?;
 
         query = query
-            .filter(deleted.eq(false))
-            .filter(start.gt(filter_from))
-            .filter(project.ne_all(self.ignored_projects.to_vec()))
+            .filter(project.ne_all(with_descendants(&self.ignored_projects)?))
             .order_by(start.desc());
 
         if !self.projects.is_empty() {
-            query = query.filter(project.eq_any(self.projects.to_vec()));
+            query = query.filter(project.eq_any(with_descendants(&self.projects)?));
         }
 
         if !&self.tags.is_empty() {
@@ -225,91 +341,84 @@ impl MyCommand for LogSubcommand {
             query = query.filter(not(tags.like(format!("%{}%", tag))));
         }
 
-        if filter_end > Local::now().naive_local() {
-            if !self.current {
-                query = query.filter(not(end.is_null()));
-            }
-        } else {
-            query = query.filter(end.lt(filter_end));
+        if let Some(text) = &self.grep {
+            query = query.filter(note.like(format!("%{}%", escape_like(text))).escape('\\'));
         }
 
-        let results = query
-            .load::<Frame>(&mut conn)
-            .expect("Error loading frames");
+        let results = query.load::<Frame>(&mut conn).map_err(Error::Query)?;
 
-        let mut actual_day: Option<NaiveDate> = None;
-        let mut list: Vec<Display> = Vec::new();
+        let mut content: Vec<u8> = vec![];
+        write_frames(&mut content, results, self.reverse, &config)?;
 
-        for frame in results {
-            let cloned_start = frame.start.date();
-            if actual_day.is_none() || actual_day.unwrap() != frame.start.date() {
-                if self.reverse {
-                    list.insert(0, Display::new(cloned_start, vec![frame]));
-                } else {
-                    list.push(Display::new(cloned_start, vec![frame]));
-                }
-                actual_day = Some(cloned_start);
+        if self.pager || config.log_pager {
+            page(output, &content)
+        } else {
+            output.out.write_all(&content)?;
+            Ok(())
+        }
+    }
+}
+
+pub fn write_frames(
+    out: &mut dyn Write,
+    frames: Vec<Frame>,
+    reverse: bool,
+    config: &AppConfig,
+) -> Result<()> {
+    let mut actual_day: Option<NaiveDate> = None;
+    let mut list: Vec<Display> = Vec::new();
+
+    for frame in frames {
+        let cloned_start = frame.start.date();
+        if actual_day.is_none() || actual_day.unwrap() != frame.start.date() {
+            if reverse {
+                list.insert(0, Display::new(cloned_start, vec![frame]));
             } else {
-                if self.reverse {
-                    list.last_mut().unwrap().insert_frame(frame);
-                } else {
-                    list.last_mut().unwrap().add_frame(frame);
-                }
+                list.push(Display::new(cloned_start, vec![frame]));
+            }
+            actual_day = Some(cloned_start);
+        } else {
+            if reverse {
+                list.last_mut().unwrap().insert_frame(frame);
+            } else {
+                list.last_mut().unwrap().add_frame(frame);
             }
         }
+    }
 
-        for mut display in list {
-            let duration = display.total_duration();
+    for mut display in list {
+        let duration = display.total_duration();
 
+        writeln!(
+            out,
+            "{} ({})",
+            display.date.format(&config.date_format).to_string().cyan(),
+            format_duration_with(&duration, &config.duration_format).green()
+        )?;
+
+        for frame in display.frames {
+            let now = Local::now().naive_local();
+            let frame_duration = frame.end.unwrap_or(now) - frame.start;
             writeln!(
-                output.out,
-                "{} ({})",
-                format!(
-                    "{} {} {} {}",
-                    display.date.weekday(),
-                    display.date.day(),
-                    display.date.month(),
-                    display.date.year()
-                )
-                .cyan(),
-                format!(
-                    "{}h {}m {}s",
-                    duration.num_hours(),
-                    format!(
-                        "{:02}",
-                        duration.num_minutes() - (duration.num_hours() * 60)
-                    ),
-                    format!(
-                        "{:02}",
-                        duration.num_seconds() - (duration.num_minutes() * 60)
-                    )
-                )
-                .green()
+                out,
+                "\t{}\t{} to {}\t{}\t{}{}",
+                &frame.id[..7].to_string().bright_black(),
+                frame.start.format(&config.time_format).to_string().green(),
+                frame
+                    .end
+                    .unwrap_or(now)
+                    .format(&config.time_format)
+                    .to_string()
+                    .green(),
+                format_duration_with(&frame_duration, &config.duration_format),
+                frame.project.purple(),
+                match &frame.note {
+                    Some(text) => format!("\t{}", text.italic()),
+                    None => "".to_string(),
+                }
             )?;
-
-            for frame in display.frames {
-                let now = Local::now().naive_local();
-                let frame_duration = frame.end.unwrap_or(now) - frame.start;
-                writeln!(
-                    output.out,
-                    "\t{}\t{} to {}\t{}h {}m {}s\t{}",
-                    &frame.id[..7].to_string().bright_black(),
-                    frame.start.format("%H:%M").to_string().green(),
-                    frame.end.unwrap_or(now).format("%H:%M").to_string().green(),
-                    frame_duration.num_hours(),
-                    format!(
-                        "{:02}",
-                        frame_duration.num_minutes() - (frame_duration.num_hours() * 60)
-                    ),
-                    format!(
-                        "{:02}",
-                        frame_duration.num_seconds() - (frame_duration.num_minutes() * 60)
-                    ),
-                    frame.project.purple()
-                )?;
-            }
         }
-
-        Ok(())
     }
+
+    Ok(())
 }
@@ -0,0 +1,147 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use aze::error::EXIT_CODES;
+use clap::CommandFactory;
+use clap_mangen::roff::{bold, roman, Roff};
+use clap_mangen::Man;
+use colored::Colorize;
+
+use super::help::TOPICS;
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Print or write the man pages of aze and its subcommands.",
+    after_help = "Example:\n\n$ aze man start | man -l -\n$ aze man --dir ~/.local/share/man/man1"
+)]
+pub struct ManSubcommand {
+    #[clap(
+        help = "Subcommand whose man page is printed. Prints the page of aze itself by default."
+    )]
+    pub subcommand: Option<String>,
+
+    #[clap(
+        help = "Write the man pages of aze and all its subcommands into this directory instead.",
+        short = 'd',
+        long = "dir",
+        conflicts_with = "subcommand"
+    )]
+    pub dir: Option<PathBuf>,
+}
+
+/// Splits help text into paragraphs, keeping examples and lists which span
+/// several lines as they are and joining the lines of prose.
+fn write_paragraphs(page: &mut Roff, text: &str) {
+    for (index, paragraph) in text.split("\n\n").enumerate() {
+        if index > 0 {
+            page.control("PP", []);
+        }
+
+        let preformatted = paragraph
+            .lines()
+            .any(|line| line.starts_with('$') || line.starts_with(' ') || line.starts_with('\t'));
+
+        if preformatted {
+            page.control("nf", []);
+            for line in paragraph.lines() {
+                page.text([roman(line.replace('\t', "    "))]);
+            }
+            page.control("fi", []);
+        } else {
+            let joined: Vec<&str> = paragraph.lines().map(|line| line.trim()).collect();
+            page.text([roman(joined.join(" "))]);
+        }
+    }
+}
+
+/// The man page generated from the command, followed by the help topics on
+/// the page of aze itself and the exit codes.
+fn render_page(command: clap::Command, topics: bool) -> Result<Vec<u8>> {
+    let title = command.get_name().to_uppercase();
+    // The NAME section only takes the first line of the about, the whole of
+    // it goes into the DESCRIPTION.
+    let command = match command.get_about() {
+        Some(about) if command.get_long_about().is_none() => command
+            .long_about(about)
+            .about(about.lines().next().unwrap_or_default()),
+        _ => command,
+    };
+
+    let mut content = vec![];
+    Man::new(command)
+        .title(title)
+        .source(format!("aze {}", env!("CARGO_PKG_VERSION")))
+        .manual("aze Manual")
+        .render(&mut content)?;
+
+    let mut page = Roff::new();
+    if topics {
+        for topic in &TOPICS {
+            page.control("SH", [topic.title]);
+            write_paragraphs(&mut page, topic.text);
+        }
+    }
+
+    page.control("SH", ["EXIT STATUS"]);
+    for (code, meaning) in [
+        (0, "the command succeeded"),
+        (2, "the arguments are invalid"),
+    ]
+    .into_iter()
+    .chain(EXIT_CODES)
+    {
+        page.control("TP", []);
+        page.text([bold(code.to_string())]);
+        page.text([roman(meaning)]);
+    }
+    page.to_writer(&mut content)?;
+
+    Ok(content)
+}
+
+/// The man page of a subcommand, named aze-<subcommand>.
+fn render_subcommand_page(command: &clap::Command, name: &str) -> Result<Vec<u8>> {
+    let subcommand = command.find_subcommand(name).unwrap().clone();
+    render_page(subcommand.name(format!("aze-{}", name)), false)
+}
+
+impl MyCommand for ManSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let command = crate::Cli::command();
+
+        let names: Vec<String> = command
+            .get_subcommands()
+            .filter(|subcommand| !subcommand.is_hide_set())
+            .map(|subcommand| subcommand.get_name().to_string())
+            .collect();
+
+        if let Some(dir) = &self.dir {
+            fs::create_dir_all(dir)?;
+            fs::write(dir.join("aze.1"), render_page(command.clone(), true)?)?;
+            for name in &names {
+                let page = render_subcommand_page(&command, name)?;
+                fs::write(dir.join(format!("aze-{}.1", name)), page)?;
+            }
+
+            writeln!(
+                output.out,
+                "Wrote {} man pages to {}",
+                (names.len() + 1).to_string().green(),
+                dir.display().to_string().purple()
+            )?;
+            return Ok(());
+        }
+
+        let page = match &self.subcommand {
+            None => render_page(command, true)?,
+            Some(name) if names.contains(name) => render_subcommand_page(&command, name)?,
+            Some(name) => return Err(anyhow!("No subcommand '{}'", name)),
+        };
+        output.out.write_all(&page)?;
+
+        Ok(())
+    }
+}
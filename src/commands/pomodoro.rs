@@ -0,0 +1,150 @@
+use std::io::Write;
+use std::thread;
+use std::time::Instant;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use aze::cli::convert_tags;
+use aze::cli::parse_duration;
+use aze::service::frame::find_frame;
+use aze::service::frame::start_frame;
+use aze::service::frame::stop_frame;
+use chrono::{Duration, Local};
+use colored::Colorize;
+
+use super::start::check_start;
+use super::start::stop_frames;
+use super::start::stop_on_start;
+use super::MyCommand;
+
+const POMODORO_TAG: &str = "pomodoro";
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Track work in pomodoro cycles of work and break intervals.",
+    after_help = "Every work interval is recorded as a frame tagged with 'pomodoro'. Default intervals are configured with 'pomodoro_work', 'pomodoro_break' and 'pomodoro_cycles'.\n\nExample:\n\n$ aze pomodoro apollo11 +brakes --cycles 2\n\nAs with 'aze start', a running frame is stopped with --stop or when 'stop_on_start' is configured."
+)]
+pub struct PomodoroSubcommand {
+    #[clap(help = "Name of the project which should be used to add time.")]
+    pub project: String,
+
+    #[clap(help = "Tag(s) which should be added to the activity. Each tag has to be prepended with a plus sign.", value_parser = convert_tags)]
+    pub tags: Vec<String>,
+
+    #[clap(help = "Length of a work interval, e.g. 25m. Defaults to the configured 'pomodoro_work'.", display_order = 1, short = 'w', long = "work", value_parser = parse_duration)]
+    pub work: Option<Duration>,
+
+    #[clap(help = "Length of a break, e.g. 5m. Defaults to the configured 'pomodoro_break'.", display_order = 2, short = 'b', long = "break", value_parser = parse_duration)]
+    pub pause: Option<Duration>,
+
+    #[clap(
+        help = "Number of work intervals. Defaults to the configured 'pomodoro_cycles'.",
+        display_order = 3,
+        short = 'c',
+        long = "cycles"
+    )]
+    pub cycles: Option<u32>,
+
+    #[clap(
+        help = "Stop the running frame when a work interval starts.",
+        long = "stop",
+        display_order = 4,
+        conflicts_with = "no-stop"
+    )]
+    pub stop: bool,
+
+    #[clap(
+        help = "Fail if a frame is already running, even if 'stop_on_start' is configured.",
+        long = "no-stop",
+        display_order = 5
+    )]
+    pub no_stop: bool,
+}
+
+fn config_duration(value: &str, name: &str) -> Result<Duration> {
+    parse_duration(value).map_err(|error| anyhow!("Invalid {} in config: {}", name, error))
+}
+
+fn countdown(out: &mut dyn Write, label: &str, duration: &Duration) -> Result<()> {
+    let end = Instant::now() + duration.to_std()?;
+
+    loop {
+        let remaining = end.saturating_duration_since(Instant::now());
+        let seconds = remaining.as_millis().div_ceil(1000);
+        write!(
+            out,
+            "\r{} {:02}:{:02} remaining",
+            label,
+            seconds / 60,
+            seconds % 60
+        )?;
+        out.flush()?;
+
+        if remaining.is_zero() {
+            break;
+        }
+        thread::sleep(remaining.min(std::time::Duration::from_secs(1)));
+    }
+    writeln!(out)?;
+
+    Ok(())
+}
+
+impl MyCommand for PomodoroSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let config = self.config()?;
+        let work = match self.work {
+            Some(work) => work,
+            None => config_duration(&config.pomodoro_work, "pomodoro_work")?,
+        };
+        let pause = match self.pause {
+            Some(pause) => pause,
+            None => config_duration(&config.pomodoro_break, "pomodoro_break")?,
+        };
+        let cycles = self.cycles.unwrap_or(config.pomodoro_cycles);
+
+        if work <= Duration::zero() || pause < Duration::zero() {
+            return Err(anyhow!("Pomodoro intervals have to be positive"));
+        }
+
+        let stop_running = stop_on_start(&config, self.stop, self.no_stop);
+
+        let mut tags = self.tags.to_owned();
+        if !tags.iter().any(|tag| tag == POMODORO_TAG) {
+            tags.push(POMODORO_TAG.to_string());
+        }
+
+        for cycle in 1..=cycles {
+            let started_at = Local::now().naive_local();
+            let stopped = check_start(&config, &self.project, &started_at, stop_running)?;
+            stop_frames(output.out, &stopped, &started_at)?;
+
+            let id = start_frame(&started_at, &self.project, tags.to_owned(), None)?;
+            writeln!(
+                output.out,
+                "Pomodoro {}/{}: working on {} [{}]",
+                cycle,
+                cycles,
+                self.project.purple(),
+                tags.join(", ").blue()
+            )?;
+
+            countdown(output.out, &"Work".green().to_string(), &work)?;
+
+            let frame = find_frame(&id).with_context(|| format!("Frame {} disappeared", id))?;
+            if frame.end.is_none() {
+                stop_frame(&frame, &Local::now().naive_local())
+                    .with_context(|| format!("Could not stop project {}", frame.project))?;
+            }
+
+            if cycle < cycles {
+                countdown(output.out, &"Break".cyan().to_string(), &pause)?;
+            }
+        }
+
+        writeln!(output.out, "Finished {} pomodoro(s).", cycles)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use std::fs;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use aze::error::Error;
+use colored::Colorize;
+
+use super::MyCommand;
+use crate::config::{
+    active_profile, config_dir, profile_config_path, profiles, validate_profile_name,
+    DEFAULT_PROFILE,
+};
+
+#[derive(clap::Subcommand, Debug)]
+pub enum ProfileAction {
+    #[clap(about = "List all profiles, the active one marked with a star.")]
+    List,
+    #[clap(about = "Create a new profile with a copy of the current configuration.")]
+    Create {
+        #[clap(help = "Name of the profile, e.g. work.")]
+        name: String,
+    },
+    #[clap(about = "Make the given profile the active one.")]
+    Switch {
+        #[clap(help = "Name of the profile.")]
+        name: String,
+    },
+}
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Manage profiles with separate configuration and frames.",
+    after_help = "The active profile is taken from --profile, then the AZE_PROFILE environment variable, then the profile selected with 'aze profile switch'.\n\nThe frames of a profile are kept in profiles/<name>/frames.db under data_dir. Its configuration is kept in profiles/<name>.toml in the configuration directory instead, as data_dir itself is read from that configuration.\n\nExample:\n\n$ aze profile create side-projects\n$ aze --profile side-projects start blog"
+)]
+pub struct ProfileSubcommand {
+    #[clap(subcommand)]
+    pub action: ProfileAction,
+}
+
+pub fn ensure_profile_exists(profile: &str) -> Result<()> {
+    if profile != DEFAULT_PROFILE && !profile_config_path(profile)?.exists() {
+        return Err(Error::ProfileNotFound(profile.to_string()).into());
+    }
+
+    Ok(())
+}
+
+impl MyCommand for ProfileSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        match &self.action {
+            ProfileAction::List => {
+                let active = active_profile()?;
+                for profile in profiles()? {
+                    if profile == active {
+                        writeln!(output.out, "* {}", profile.green())?;
+                    } else {
+                        writeln!(output.out, "  {}", profile)?;
+                    }
+                }
+            }
+            ProfileAction::Create { name } => {
+                validate_profile_name(name)?;
+                if profiles()?.contains(name) {
+                    return Err(anyhow!("Profile {} already exists", name));
+                }
+
+                fs::create_dir_all(config_dir()?.join("profiles"))?;
+                confy::store_path(profile_config_path(name)?, self.config()?)?;
+                writeln!(output.out, "Created profile {}", name.green())?;
+            }
+            ProfileAction::Switch { name } => {
+                ensure_profile_exists(name)?;
+
+                let config_dir = config_dir()?;
+                fs::create_dir_all(&config_dir)?;
+                fs::write(config_dir.join("profile"), name)?;
+                writeln!(output.out, "Switched to profile {}", name.green())?;
+            }
+        }
+
+        Ok(())
+    }
+}
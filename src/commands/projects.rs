@@ -1,5 +1,9 @@
 use anyhow::Result;
+use chrono::Local;
 use colored::Colorize;
+use aze::display::project::ProjectTree;
+use aze::format_duration_with;
+use aze::service::frame::find_all as find_all_frames;
 use aze::service::project::find_all;
 
 use super::MyCommand;
@@ -7,15 +11,42 @@ use super::MyCommand;
 #[derive(clap::Args, Debug)]
 #[clap(
     about = "Display the list of all the existing projectst.",
-    after_help = "Example:\n\n$ aze projects\napollo11\nhubble\nvoyager1\nvoyager2"
+    after_help = "Example:\n\n$ aze projects\napollo11\nhubble\nvoyager1\nvoyager2\n\nProjects can be nested by separating them with a slash:\n\n$ aze projects --tree\nnasa  3h 00m 00s\n├── apollo11  1h 00m 00s\n└── voyager  2h 00m 00s\n    ├── voyager1  1h 30m 00s\n    └── voyager2  0h 30m 00s"
 )]
 pub struct ProjectsSubcommand {
-
+    #[clap(
+        short = 't',
+        long = "tree",
+        display_order = 1,
+        help = "Display projects as a tree, using '/' as separator between parent and child projects, with the time spent on each project including its sub-projects."
+    )]
+    pub tree: bool,
 }
 
 impl MyCommand for ProjectsSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
-        let projects = find_all();
+        let projects = find_all()?;
+
+        if self.tree {
+            let now = Local::now().naive_local();
+            let mut tree = ProjectTree::new(projects);
+            for frame in find_all_frames()? {
+                tree.add_duration(&frame.project, frame.end.unwrap_or(now) - frame.start);
+            }
+
+            let duration_format = self.config()?.duration_format;
+            for (prefix, name, duration) in tree.lines() {
+                writeln!(
+                    output.out,
+                    "{}{}  {}",
+                    prefix,
+                    name.purple(),
+                    format_duration_with(&duration, &duration_format).green()
+                )?;
+            }
+
+            return Ok(());
+        }
 
         for project in projects {
             writeln!(output.out, "{}", project.purple())?;
@@ -1,10 +1,9 @@
-use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
-use colored::Colorize;
-use dialoguer::Confirm;
-use aze::service::{project::find_all, frame::find_frame_by_short};
+use aze::service::frame::find_frame_by_short;
 use aze::schema::frames;
 use aze::database::establish_connection;
+use aze::error::Error;
 use crate::diesel::RunQueryDsl;
 use crate::diesel::ExpressionMethods;
 
@@ -31,15 +30,9 @@ pub struct RemoveSubcommand {
 }
 
 impl MyCommand for RemoveSubcommand {
-    fn run(&self, output: super::Output) -> Result<()> {
+    fn run(&self, _output: super::Output) -> Result<()> {
 
-        let frame_un = find_frame_by_short(&self.id);
-
-        if frame_un.is_err() {
-            return Err(anyhow!("No frame found with id {}.", self.id));
-        }
-
-        let frame = frame_un.unwrap();
+        let frame = find_frame_by_short(&self.id)?;
         /*if !self.force {
             write!(
                 output.out,
@@ -60,12 +53,11 @@ impl MyCommand for RemoveSubcommand {
                 frames::deleted.eq(true),
             ));
 
-            let mut conn = establish_connection();
-            let result = update_satement.execute(&mut conn);
-
-            if result.is_err() {
-                return Err(anyhow!("Could not save frame with id {}", frame.id));
-            }
+            let mut conn = establish_connection()?;
+            update_satement
+                .execute(&mut conn)
+                .map_err(Error::Query)
+                .with_context(|| format!("Could not save frame with id {}", frame.id))?;
         }
 
         Ok(())
@@ -0,0 +1,92 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use aze::database::establish_connection;
+use aze::error::Error;
+use aze::models::Frame;
+use diesel::associations::HasTable;
+use regex::Regex;
+use regex::RegexBuilder;
+
+use crate::diesel::ExpressionMethods;
+use crate::diesel::QueryDsl;
+use crate::diesel::RunQueryDsl;
+
+use super::log::write_frames;
+use super::log::TimeRange;
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Search frames by project, tags and notes.",
+    after_help = "Example:\n\n$ aze search review --all\n$ aze search --regex '^ABC-[0-9]+' --month"
+)]
+pub struct SearchSubcommand {
+    #[clap(help = "Text which is searched case insensitive in projects, tags and notes.")]
+    pub query: String,
+
+    #[clap(
+        short = 'e',
+        long = "regex",
+        display_order = 10,
+        help = "Interpret the query as regular expression."
+    )]
+    pub regex: bool,
+
+    #[clap(flatten)]
+    pub range: TimeRange,
+
+    #[clap(
+        short = 'r',
+        long = "reverse",
+        display_order = 2,
+        help = "Reverse the order of the days in output."
+    )]
+    pub reverse: bool,
+}
+
+impl SearchSubcommand {
+    fn matcher(&self) -> Result<Regex> {
+        let pattern = if self.regex {
+            self.query.to_string()
+        } else {
+            regex::escape(&self.query)
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|error| anyhow!("Invalid regular expression: {}", error))
+    }
+}
+
+impl MyCommand for SearchSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        use aze::schema::frames::dsl::*;
+
+        let matcher = self.matcher()?;
+
+        let mut conn = establish_connection()?;
+
+        let config = self.config()?;
+        let query = self
+            .range
+            .filter(frames::table().into_boxed(), &config)?
+            .order_by(start.desc());
+
+        let results: Vec<Frame> = query
+            .load::<Frame>(&mut conn)
+            .map_err(Error::Query)?
+            .into_iter()
+            .filter(|frame| {
+                matcher.is_match(&frame.project)
+                    || frame.tags.values().iter().any(|tag| matcher.is_match(tag))
+                    || frame
+                        .note
+                        .as_ref()
+                        .is_some_and(|text| matcher.is_match(text))
+            })
+            .collect();
+
+        write_frames(output.out, results, self.reverse, &config)
+    }
+}
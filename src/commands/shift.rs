@@ -0,0 +1,109 @@
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use aze::cli::parse_duration;
+use aze::cli::parse_to_datetime;
+use aze::models::Frame;
+use aze::service::frame::find_between;
+use aze::service::frame::find_frame_by_short;
+use aze::service::frame::overlapping_frames;
+use aze::service::frame::shift_frames;
+use chrono::{Duration, Local, NaiveDateTime};
+use colored::Colorize;
+
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Move frames in time by shifting their start and end.",
+    after_help = "Example:\n\n$ aze shift f1c4815 9d1a989 --by +15m\n$ aze shift --from \"2018-03-20\" --to \"2018-03-21\" --by -1h"
+)]
+pub struct ShiftSubcommand {
+    #[clap(help = "Frame id(s) which should be shifted.", conflicts_with_all = &["from", "to"])]
+    pub ids: Vec<String>,
+
+    #[clap(help = "Duration by which the frames are shifted, e.g. +15m, -1h or 1h30m.", display_order = 1, long = "by", value_parser = parse_duration, allow_hyphen_values = true, required = true)]
+    pub by: Duration,
+
+    #[clap(help = "Shift all frames starting at or after this date.", display_order = 2, short = 'f', long = "from", value_parser = parse_to_datetime)]
+    pub from: Option<NaiveDateTime>,
+
+    #[clap(help = "Shift all frames starting at or before this date.", display_order = 3, short = 't', long = "to", value_parser = parse_to_datetime)]
+    pub to: Option<NaiveDateTime>,
+}
+
+impl ShiftSubcommand {
+    fn selected_frames(&self) -> Result<Vec<Frame>> {
+        if self.ids.is_empty() {
+            if self.from.is_none() && self.to.is_none() {
+                return Err(anyhow!("Either frame ids or --from/--to have to be given"));
+            }
+            return Ok(find_between(self.from.as_ref(), self.to.as_ref(), &[])?);
+        }
+
+        let mut selected: Vec<Frame> = vec![];
+        for id in &self.ids {
+            let frame = find_frame_by_short(id)?;
+            if !selected.iter().any(|other| other.id == frame.id) {
+                selected.push(frame);
+            }
+        }
+
+        Ok(selected)
+    }
+}
+
+impl MyCommand for ShiftSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let selected = self.selected_frames()?;
+        if selected.is_empty() {
+            return Err(anyhow!("No frames found for the given filters"));
+        }
+
+        let now = Local::now().naive_local();
+        let selected_ids: Vec<String> = selected.iter().map(|frame| frame.id.to_string()).collect();
+        for frame in &selected {
+            let out_of_range = || anyhow!("Frame {} would be moved too far", &frame.id[..7]);
+            let start = frame
+                .start
+                .checked_add_signed(self.by)
+                .ok_or_else(out_of_range)?;
+            let end = match frame.end {
+                Some(end) => Some(end.checked_add_signed(self.by).ok_or_else(out_of_range)?),
+                None => None,
+            };
+
+            if start > now || end.is_some_and(|end| end > now) {
+                return Err(anyhow!(
+                    "Frame {} would be moved into the future",
+                    &frame.id[..7]
+                ));
+            }
+
+            if let Some(other) = overlapping_frames(&start, end.as_ref(), &selected_ids)?.first() {
+                return Err(anyhow!(
+                    "Frame {} would overlap with frame {}",
+                    &frame.id[..7],
+                    &other.id[..7]
+                ));
+            }
+        }
+
+        shift_frames(&selected, &self.by).context("Could not shift frames")?;
+
+        for frame in &selected {
+            writeln!(
+                output.out,
+                "Shifted frame {} ({}) to {}",
+                &frame.id[..7].bright_black(),
+                frame.project.purple(),
+                (frame.start + self.by)
+                    .format(&self.config()?.datetime_format)
+                    .to_string()
+                    .green()
+            )?;
+        }
+
+        Ok(())
+    }
+}
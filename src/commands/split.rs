@@ -0,0 +1,77 @@
+use anyhow::anyhow;
+use anyhow::Context;
+use anyhow::Result;
+use aze::cli::convert_tags;
+use aze::cli::parse_to_datetime;
+use aze::service::frame::find_frame_by_short;
+use aze::service::frame::split_frame;
+use chrono::{Local, NaiveDateTime};
+use colored::Colorize;
+
+use super::MyCommand;
+
+#[derive(clap::Args, Debug)]
+#[clap(
+    about = "Split a frame into two frames at the given time.",
+    after_help = "Example:\n\n$ aze split f1c4815 --at \"2018-03-20 12:30\"\n$ aze split f1c4815 --at \"2018-03-20 12:30\" --project hubble +review"
+)]
+pub struct SplitSubcommand {
+    #[clap(help = "Frame id which should be split.")]
+    pub id: String,
+
+    #[clap(help = "Tag(s) of the second frame. Each tag has to be prepended with a plus sign. Defaults to the tags of the split frame.", value_parser = convert_tags)]
+    pub tags: Vec<String>,
+
+    #[clap(help = "Time at which the frame is split.", display_order = 1, long = "at", value_parser = parse_to_datetime, required = true)]
+    pub at: NaiveDateTime,
+
+    #[clap(
+        short = 'p',
+        long = "project",
+        display_order = 2,
+        help = "Project of the second frame. Defaults to the project of the split frame."
+    )]
+    pub project: Option<String>,
+}
+
+impl MyCommand for SplitSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        let frame = find_frame_by_short(&self.id)?;
+
+        let end = frame.end.unwrap_or_else(|| Local::now().naive_local());
+        if self.at <= frame.start || self.at >= end {
+            return Err(anyhow!(
+                "Split time must be between start and end of frame {}",
+                &frame.id[..7]
+            ));
+        }
+
+        let project = self
+            .project
+            .to_owned()
+            .unwrap_or_else(|| frame.project.to_string());
+        let tags = if self.tags.is_empty() {
+            frame.tags.values()
+        } else {
+            self.tags.to_owned()
+        };
+
+        let id = split_frame(&frame, &self.at, &project, tags)
+            .with_context(|| format!("Could not split frame with id {}", frame.id))?;
+
+        writeln!(
+            output.out,
+            "Split frame {} at {} into {} and {} ({})",
+            &frame.id[..7].bright_black(),
+            self.at
+                .format(&self.config()?.datetime_format)
+                .to_string()
+                .green(),
+            &frame.id[..7].bright_black(),
+            &id[..7].bright_black(),
+            project.purple()
+        )?;
+
+        Ok(())
+    }
+}
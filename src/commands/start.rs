@@ -1,26 +1,36 @@
+use std::env;
+use std::io::Write;
+
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
 use chrono::{Local, NaiveDateTime};
 use colored::Colorize;
+use aze::ago;
 use aze::cli::convert_tags;
 use aze::cli::parse_to_datetime;
 use aze::cli::process_project;
 use aze::cli::process_tags;
+use aze::models::Frame;
+use aze::service::directory::{load_defaults, DEFAULTS_FILE};
 use aze::service::frame::frame_start_collides;
 use aze::service::frame::last_finished_frame;
 use aze::service::frame::last_started_frame;
+use aze::service::frame::running_frames;
 use aze::service::frame::start_frame;
+use aze::service::frame::stop_frame;
 
 use super::MyCommand;
+use crate::config::AppConfig;
 
 #[derive(clap::Args, Debug)]
 #[clap(
     about = "Start monitoring time for the given project.",
-    after_help = "Example:\n\n$ aze start apollo11 +module +brakes --no-gap\nStarting project apollo11 [module, brakes] at 16:34"
+    after_help = "Example:\n\n$ aze start apollo11 +module +brakes --no-gap\nStarting project apollo11 [module, brakes] at 16:34\n\nProject and tags default to the 'project' and 'tags' of the nearest .aze.toml in the current or a parent directory.\n\nSet 'stop_on_start = true' in the config to always stop the running frame, as with --stop."
 )]
 pub struct StartSubcommand {
-    #[clap(help = "Name of the project which should be used to add time.")]
-    pub project: String,
+    #[clap(help = "Name of the project which should be used to add time. Defaults to the project of the nearest .aze.toml.")]
+    pub project: Option<String>,
 
     #[clap(help = "Tag(s) which should be added to the activity. Each tag has to be prepended with a plus sign.", value_parser = convert_tags)]
     pub tags: Vec<String>,
@@ -28,6 +38,14 @@ pub struct StartSubcommand {
     #[clap(help = "Start frame at this time.", display_order = 1, long = "at", value_parser = parse_to_datetime)]
     pub at: Option<NaiveDateTime>,
 
+    #[clap(
+        short = 'n',
+        long = "note",
+        display_order = 2,
+        help = "Note which should be added to the activity."
+    )]
+    pub note: Option<String>,
+
     #[clap(
         short = 'c',
         display_order = 4,
@@ -53,60 +71,184 @@ pub struct StartSubcommand {
         conflicts_with_all = &["at"]
     )]
     pub no_gap: bool,
+
+    #[clap(
+        help = "Stop the running frame at the start time of the new one.",
+        long = "stop",
+        display_order = 6,
+        conflicts_with = "no-stop"
+    )]
+    pub stop: bool,
+
+    #[clap(
+        help = "Fail if a frame is already running, even if 'stop_on_start' is configured.",
+        long = "no-stop",
+        display_order = 7
+    )]
+    pub no_stop: bool,
+}
+
+impl StartSubcommand {
+    /// Project and tags from the arguments. Without a project, both default to
+    /// the nearest `.aze.toml`; a project given as `+tag` is treated as a tag.
+    fn project_and_tags(&self) -> Result<(String, Vec<String>)> {
+        let mut project = self.project.to_owned();
+        let mut tags = self.tags.to_owned();
+
+        if let Some(tag) = project.as_deref().and_then(|name| name.strip_prefix('+')) {
+            tags.insert(0, tag.to_string());
+            project = None;
+        }
+
+        if let Some(project) = project {
+            return Ok((project, tags));
+        }
+
+        let defaults = load_defaults(&env::current_dir()?)?;
+        let project = defaults.project.ok_or_else(|| {
+            anyhow!("No project given and no {} with a project found", DEFAULTS_FILE)
+        })?;
+        if tags.is_empty() {
+            tags = defaults.tags;
+        }
+
+        Ok((project, tags))
+    }
+}
+
+/// Whether running frames are stopped when a new one starts: `--stop` and
+/// `--no-stop` win over the configured `stop_on_start`.
+pub fn stop_on_start(config: &AppConfig, stop: bool, no_stop: bool) -> bool {
+    if stop {
+        true
+    } else if no_stop {
+        false
+    } else {
+        config.stop_on_start
+    }
+}
+
+/// Checks that a frame of the project can start at the given time and returns
+/// the running frames which have to be stopped first.
+pub fn check_start(
+    config: &AppConfig,
+    project: &str,
+    started_at: &NaiveDateTime,
+    stop_running: bool,
+) -> Result<Vec<Frame>> {
+    let stopped = if stop_running {
+        running_frames()?
+    } else {
+        vec![]
+    };
+    if let Some(frame) = stopped.iter().find(|frame| frame.start > *started_at) {
+        return Err(anyhow!(
+            "Project {} can't be stopped before it started",
+            frame.project
+        ));
+    }
+
+    if config.allow_concurrent_frames && !stop_running {
+        if let Some(result) = running_frames()?
+            .into_iter()
+            .find(|frame| frame.project == project)
+        {
+            return Err(anyhow!(format!(
+                "Project {} is already started",
+                result.project
+            )));
+        }
+    } else {
+        if frame_start_collides(started_at)? {
+            return Err(anyhow!("Start collides with existing frame"));
+        }
+
+        if let (false, Some(result)) = (stop_running, last_started_frame()?) {
+            return Err(anyhow!(format!(
+                "Project {} is already started",
+                result.project
+            )));
+        }
+    }
+
+    Ok(stopped)
+}
+
+/// Stops the frames returned by `check_start` at the start of the new one.
+pub fn stop_frames(
+    out: &mut dyn Write,
+    frames: &[Frame],
+    started_at: &NaiveDateTime,
+) -> Result<()> {
+    for frame in frames {
+        stop_frame(frame, started_at)
+            .with_context(|| format!("Could not stop project {}", frame.project))?;
+        writeln!(
+            out,
+            "Stopping project {}, started {} and stopped {}",
+            frame.project,
+            ago(frame.start),
+            ago(*started_at)
+        )?;
+    }
+
+    Ok(())
 }
 
 impl MyCommand for StartSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
-        let project_string = self.project.to_string();
+        let (project_string, tags) = self.project_and_tags()?;
         let at = self.at;
 
         let now = Local::now().naive_local();
-        let started_at: NaiveDateTime;
-        if at.is_some() {
-            started_at = at.unwrap();
+        let started_at: NaiveDateTime = if let Some(at) = at {
             // TODO: check if at is in the future
+            at
         } else if self.no_gap {
-            let last_finished = last_finished_frame();
+            let last_finished = last_finished_frame()?;
             if last_finished.is_none() {
                 return Err(anyhow!("No finished frame found, 'no-gap' is not possible"));
             }
-            started_at = last_finished.unwrap().end.unwrap();
+            last_finished.unwrap().end.unwrap()
         } else {
-            started_at = now;
-        }
-
-        if frame_start_collides(&started_at) {
-            return Err(anyhow!("Start collides with existing frame"));
-        }
+            now
+        };
 
-        let result = last_started_frame();
-        if result.is_some() {
-            return Err(anyhow!(format!(
-                "Project {} is already started",
-                result.unwrap().project
-            )));
-        }
+        let config = self.config()?;
+        let stopped = check_start(
+            &config,
+            &project_string,
+            &started_at,
+            stop_on_start(&config, self.stop, self.no_stop),
+        )?;
 
-        if !process_project(self.project.to_string(), self.confirm_project) {
+        if !process_project(project_string.to_string(), self.confirm_project)? {
             return Err(anyhow!("Aborted!"));
         }
-        if !process_tags(self.tags.to_owned(), self.confirm_tags) {
+        if !process_tags(tags.to_owned(), self.confirm_tags)? {
             return Err(anyhow!("Aborted!"));
         }
 
+        stop_frames(output.out, &stopped, &started_at)?;
+
         writeln!(
             output.out,
             "starting project {}{} at {}",
             project_string.purple(),
-            if !self.tags.is_empty() {
-                format!(" [{}]", self.tags.join(", ").blue())
+            if !tags.is_empty() {
+                format!(" [{}]", tags.join(", ").blue())
             } else {
                 "".to_string()
             },
-            started_at.format("%d.%m.%Y %H:%M").to_string().cyan(),
+            started_at.format(&config.datetime_format).to_string().cyan(),
         )?;
 
-        start_frame(&started_at, &project_string, self.tags.to_owned());
+        start_frame(
+            &started_at,
+            &project_string,
+            tags,
+            self.note.as_deref(),
+        )?;
         Ok(())
     }
 }
@@ -1,14 +1,27 @@
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::thread;
+
+use super::max_frame_duration;
 use super::MyCommand;
-use crate::config::load_config;
+use anyhow::anyhow;
 use anyhow::Result;
 use colored::Colorize;
 use aze::ago;
+use aze::error::Error;
+use aze::display::status::{render_bar, render_status, Bar, JsonStatus};
+use aze::format_clock;
+use aze::models::Frame;
 use aze::service::frame::last_started_frame;
+use aze::service::frame::overlapping_frames;
+use aze::service::frame::running_frames;
+use aze::service::idle::suggested_end;
+use chrono::{Duration, Local};
 
 #[derive(clap::Args, Debug)]
 #[clap(
-    about = "Display when the current project and the time spent since.",
-    after_help = "Example:\n\n$ aze status\nProject apollo11 [brakes] started seconds ago (2014-05-19 14:32:41+0100)"
+    about = "Display when the current project and the time spent since. Every running frame is listed on its own line.",
+    after_help = "With --format or --json nothing is printed and the exit code is 6 if no project is started.\n\nExample:\n\n$ aze status\nProject apollo11 [brakes] started seconds ago (2014-05-19 14:32:41+0100)\n\n$ aze status --format '{project} {elapsed:m}m'\napollo11 12m"
 )]
 pub struct StatusSubcommand {
     #[clap(
@@ -31,48 +44,193 @@ pub struct StatusSubcommand {
         conflicts_with_all = &["show-tags", "show-project"]
     )]
     pub show_elapsed: bool,
+
+    #[clap(
+        help = "Continuously redraw the running frames and today's totals until interrupted.",
+        display_order = 4,
+        short = 'w',
+        long = "watch",
+        conflicts_with_all = &["show-tags", "show-project", "show-elapsed"]
+    )]
+    pub watch: bool,
+
+    #[clap(
+        help = "Format the running frames with a template, e.g. '{project} {elapsed}'. Placeholders: {project}, {tags}, {note}, {id}, {start[:FORMAT]}, {elapsed[:s|m|h]}.",
+        display_order = 5,
+        short = 'f',
+        long = "format",
+        conflicts_with_all = &["show-tags", "show-project", "show-elapsed", "watch", "json"]
+    )]
+    pub format: Option<String>,
+
+    #[clap(
+        help = "Output the running frames as JSON.",
+        display_order = 6,
+        short = 'j',
+        long = "json",
+        conflicts_with_all = &["show-tags", "show-project", "show-elapsed", "watch"]
+    )]
+    pub json: bool,
+
+    #[clap(
+        help = "Output the status in the protocol of the given status bar.",
+        display_order = 7,
+        long = "bar",
+        value_enum,
+        conflicts_with_all = &["show-tags", "show-project", "show-elapsed", "watch", "json", "format"]
+    )]
+    pub bar: Option<Bar>,
 }
 
-impl MyCommand for StatusSubcommand {
-    fn run(&self, output: super::Output) -> Result<()> {
-        let result = last_started_frame();
+impl StatusSubcommand {
+    fn write_machine_readable(&self, out: &mut dyn Write, running: &[Frame]) -> Result<()> {
+        let now = Local::now().naive_local();
 
-        if result.is_none() {
-            writeln!(output.out, "No project started.")?;
+        if self.json {
+            let statuses: Vec<JsonStatus> = running
+                .iter()
+                .map(|frame| JsonStatus::new(frame, &now))
+                .collect();
+            writeln!(out, "{}", serde_json::to_string(&statuses)?)?;
             return Ok(());
         }
 
-        let frame = result.unwrap();
+        let template = self.format.as_deref().unwrap_or_default();
+        let datetime_format = self.config()?.datetime_format;
+        for frame in running {
+            let line = render_status(template, frame, &now, &datetime_format)
+                .map_err(|error| anyhow!(error))?;
+            writeln!(out, "{}", line)?;
+        }
 
-        if self.show_project {
-            writeln!(output.out, "{}", frame.project.purple())?;
-            return Ok(());
+        Ok(())
+    }
+
+    fn draw(&self, out: &mut dyn Write) -> Result<()> {
+        let now = Local::now().naive_local();
+
+        if std::io::stdout().is_terminal() {
+            write!(out, "\x1b[2J\x1b[H")?;
+        }
+
+        let running = running_frames()?;
+        if running.is_empty() {
+            writeln!(out, "No project started.")?;
         }
-        if self.show_tags {
-            writeln!(output.out, "{}", frame.tags.values().join(", ").cyan())?;
+        for frame in &running {
+            writeln!(
+                out,
+                "{}{}\t{}",
+                frame.project.purple(),
+                if !frame.tags.values().is_empty() {
+                    format!(" [{}]", frame.tags.values().join(", ").blue())
+                } else {
+                    "".to_string()
+                },
+                format_clock(&(now - frame.start)).green()
+            )?;
+        }
+
+        let midnight = now.date().and_hms_opt(0, 0, 0).unwrap();
+        let mut totals: BTreeMap<String, Duration> = BTreeMap::new();
+        for frame in overlapping_frames(&midnight, None, &[])? {
+            let total = totals
+                .entry(frame.project.to_string())
+                .or_insert_with(Duration::zero);
+            *total = *total + (frame.end.unwrap_or(now) - frame.start.max(midnight));
+        }
+
+        writeln!(out)?;
+        writeln!(out, "{}", "Today".cyan())?;
+        for (project, total) in totals {
+            writeln!(out, "\t{}\t{}", project.purple(), format_clock(&total))?;
+        }
+
+        out.flush()?;
+
+        Ok(())
+    }
+}
+
+impl MyCommand for StatusSubcommand {
+    fn run(&self, output: super::Output) -> Result<()> {
+        if self.watch {
+            loop {
+                self.draw(output.out)?;
+                thread::sleep(std::time::Duration::from_secs(1));
+            }
+        }
+
+        if let Some(bar) = self.bar {
+            let line = render_bar(
+                bar,
+                last_started_frame()?.as_ref(),
+                &Local::now().naive_local(),
+                &self.config()?.datetime_format,
+            );
+            writeln!(output.out, "{}", line)?;
             return Ok(());
         }
-        if self.show_elapsed {
-            writeln!(output.out, "{}", ago(frame.start).green())?;
+
+        let running = running_frames()?;
+
+        if self.format.is_some() || self.json {
+            if running.is_empty() {
+                return Err(Error::NoRunningFrame.into());
+            }
+            return self.write_machine_readable(output.out, &running);
+        }
+
+        if running.is_empty() {
+            writeln!(output.out, "No project started.")?;
             return Ok(());
         }
 
-        writeln!(
-            output.out,
-            "Project {}{} started {} ({})",
-            frame.project.purple(),
-            if !frame.tags.values().is_empty() {
-                format!(" [{}]", frame.tags.values().join(", ").blue())
-            } else {
-                "".to_string()
-            },
-            ago(frame.start).green(),
-            frame
-                .start
-                .format(&load_config().datetime_format)
-                .to_string()
-                .cyan()
-        )?;
+        let config = self.config()?;
+        let max = max_frame_duration(&config)?;
+        let now = Local::now().naive_local();
+
+        for frame in running {
+            if self.show_project {
+                writeln!(output.out, "{}", frame.project.purple())?;
+                continue;
+            }
+            if self.show_tags {
+                writeln!(output.out, "{}", frame.tags.values().join(", ").cyan())?;
+                continue;
+            }
+            if self.show_elapsed {
+                writeln!(output.out, "{}", ago(frame.start).green())?;
+                continue;
+            }
+
+            writeln!(
+                output.out,
+                "Project {}{} started {} ({})",
+                frame.project.purple(),
+                if !frame.tags.values().is_empty() {
+                    format!(" [{}]", frame.tags.values().join(", ").blue())
+                } else {
+                    "".to_string()
+                },
+                ago(frame.start).green(),
+                frame
+                    .start
+                    .format(&config.datetime_format)
+                    .to_string()
+                    .cyan()
+            )?;
+
+            if let Some(end) = max.and_then(|max| suggested_end(&frame, &max, &now)) {
+                writeln!(
+                    output.out,
+                    "{} project {} is running longer than the maximum frame duration. Run 'aze stop --idle' to stop it at {}.",
+                    "Warning:".yellow(),
+                    frame.project.purple(),
+                    end.format(&config.datetime_format)
+                )?;
+            }
+        }
 
         Ok(())
     }
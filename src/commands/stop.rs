@@ -1,52 +1,117 @@
 use crate::diesel::ExpressionMethods;
-use crate::diesel::QueryDsl;
 use crate::diesel::RunQueryDsl;
 use anyhow::anyhow;
+use anyhow::Context;
 use anyhow::Result;
 use chrono::{Local, NaiveDateTime};
-use diesel::OptionalExtension;
 use aze::ago;
+use aze::cli::parse_to_datetime;
 use aze::database::establish_connection;
+use aze::error::Error;
 use aze::models::Frame;
+use aze::service::frame::running_frames;
+use aze::service::idle::suggested_end;
 
+use super::max_frame_duration;
 use super::MyCommand;
 
 #[derive(clap::Args, Debug)]
+#[clap(
+    about = "Stop monitoring time for the current project.",
+    after_help = "When several frames are running, the frame to stop has to be given by its project or id. A time given instead stops the frame at that time, like '--at'."
+)]
 pub struct StopSubcommand {
+    #[clap(
+        help = "Project or frame id of the running frame which should be stopped, or the time to stop it at."
+    )]
+    pub frame: Option<String>,
+
+    #[clap(help = "Stop frame at this time.", display_order = 1, long = "at", value_parser = parse_to_datetime)]
     pub at: Option<NaiveDateTime>,
+
+    #[clap(
+        long = "idle",
+        display_order = 3,
+        conflicts_with = "at",
+        help = "If the frame ran longer than the configured 'max_frame_duration', stop it when that duration was reached instead of now."
+    )]
+    pub idle: bool,
+
+    #[clap(
+        short = 'n',
+        long = "note",
+        display_order = 2,
+        help = "Note which should be added to the activity. Appended to a note given at start."
+    )]
+    pub note: Option<String>,
+}
+
+impl StopSubcommand {
+    /// Splits the positional argument into the frame to stop and the time to
+    /// stop it at. A time is accepted there to keep `aze stop <time>` working.
+    fn target(&self) -> (Option<&String>, Option<NaiveDateTime>) {
+        match &self.frame {
+            Some(value) => match parse_to_datetime(value) {
+                Ok(time) => (None, self.at.or(Some(time))),
+                Err(_) => (Some(value), self.at),
+            },
+            None => (None, self.at),
+        }
+    }
+
+    fn select_frame(&self, target: Option<&String>) -> Result<Frame> {
+        let mut running = running_frames()?;
+
+        if let Some(target) = target {
+            running.retain(|frame| &frame.project == target || frame.id.starts_with(target));
+            if running.is_empty() {
+                return Err(Error::NoMatchingFrame(target.to_string()).into());
+            }
+        }
+
+        match running.len() {
+            0 => Err(Error::NothingToStop.into()),
+            1 => Ok(running.remove(0)),
+            _ if self.config()?.allow_concurrent_frames => Err(Error::SeveralRunningFrames(
+                running
+                    .iter()
+                    .map(|frame| format!("{} ({})", frame.project, &frame.id[..7]))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+            .into()),
+            _ => Ok(running.pop().unwrap()),
+        }
+    }
 }
 
 impl MyCommand for StopSubcommand {
     fn run(&self, output: super::Output) -> Result<()> {
         use aze::schema::frames::dsl::*;
 
-        let at = self.at;
+        let (target, at) = self.target();
+        let frame = self.select_frame(target)?;
 
         let now = Local::now().naive_local();
-        let started_at: NaiveDateTime;
-        if at.is_some() {
-            started_at = at.unwrap();
+        let at = if self.idle {
+            let max = max_frame_duration(&self.config()?)?
+                .ok_or_else(|| anyhow!("No max_frame_duration configured."))?;
+            suggested_end(&frame, &max, &now)
         } else {
-            started_at = now;
-        }
-
-        let mut conn = establish_connection();
+            at
+        };
+        let started_at: NaiveDateTime = at.unwrap_or(now);
+        let frame_note = match (&frame.note, &self.note) {
+            (Some(existing), Some(added)) => Some(format!("{}; {}", existing, added)),
+            (existing, added) => added.to_owned().or_else(|| existing.to_owned()),
+        };
 
-        let result = frames
-            .filter(deleted.eq(false))
-            .filter(end.is_null())
-            .order_by(start.desc())
-            .first::<Frame>(&mut conn)
-            .optional()
-            .expect("Error loading frames");
-
-        if result.is_none() {
-            return Err(anyhow!("No project started."));
-        }
-        let frame = result.unwrap();
-        let _result = diesel::update(&frame)
-            .set(end.eq(started_at))
-            .execute(&mut conn);
+        let mut conn = establish_connection()?;
+        diesel::update(&frame)
+            .set((end.eq(started_at), note.eq(frame_note)))
+            .execute(&mut conn)
+            .map_err(Error::Query)
+            .with_context(|| format!("Could not stop project {}", frame.project))?;
 
         writeln!(
             output.out,
@@ -1,55 +1,291 @@
-use std::{env, path::{MAIN_SEPARATOR, Path}, fs::create_dir_all};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
 
-use anyhow::Result;
-use confy::ConfyError;
+use anyhow::{anyhow, Result};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use directories_next::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{self, Error};
+
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     pub data_dir: String,
     pub datetime_format: String,
+    pub date_format: String,
+    pub time_format: String,
+    pub duration_format: String,
+    pub week_start: String,
+    pub log_range: String,
+    pub log_current: bool,
+    pub log_pager: bool,
+    pub color: String,
+    pub working_hours_start: String,
+    pub working_hours_end: String,
+    pub working_days: Vec<String>,
+    pub allow_concurrent_frames: bool,
+    pub stop_on_start: bool,
+    pub max_frame_duration: Option<String>,
+    pub pomodoro_work: String,
+    pub pomodoro_break: String,
+    pub pomodoro_cycles: u32,
 }
 
 impl AppConfig {
-    pub fn database_url(&self) -> String {
-        let path = Path::new(&self.data_dir);
+    pub fn database_url(&self) -> error::Result<String> {
+        if let Ok(url) = env::var("DATABASE_URL") {
+            return Ok(url);
+        }
+        if self.data_dir.is_empty() {
+            return Err(Error::HomeDirectory);
+        }
+
+        let profile = active_profile()?;
+        let path = if profile == DEFAULT_PROFILE {
+            Path::new(&self.data_dir).to_path_buf()
+        } else {
+            Path::new(&self.data_dir).join("profiles").join(profile)
+        };
+
+        Ok(path.join("frames.db").to_string_lossy().to_string())
+    }
 
-        if !path.exists() {
-            create_dir_all(path).expect(&format!("Failed to create data directory {}", self.data_dir));
+    pub fn working_hours(&self) -> Result<(NaiveTime, NaiveTime)> {
+        let parse = |value: &str| {
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .map_err(|_| anyhow!("Invalid working hour '{}' in config, expected HH:MM", value))
+        };
+        let start = parse(&self.working_hours_start)?;
+        let end = parse(&self.working_hours_end)?;
+
+        if end <= start {
+            return Err(anyhow!("Working hours have to end after they start"));
         }
-        let default = format!("{}{}{}", self.data_dir, MAIN_SEPARATOR, "frames.db");
 
-        env::var("DATABASE_URL").unwrap_or(default)
+        Ok((start, end))
+    }
+
+    pub fn working_days(&self) -> Result<Vec<Weekday>> {
+        self.working_days
+            .iter()
+            .map(|day| {
+                day.parse::<Weekday>()
+                    .map_err(|_| anyhow!("Invalid working day '{}' in config", day))
+            })
+            .collect()
+    }
+
+    pub fn week_start(&self) -> Result<Weekday> {
+        self.week_start
+            .parse::<Weekday>()
+            .map_err(|_| anyhow!("Invalid week_start '{}' in config", self.week_start))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        for (key, format) in [
+            ("datetime_format", &self.datetime_format),
+            ("date_format", &self.date_format),
+            ("time_format", &self.time_format),
+        ] {
+            if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                return Err(anyhow!("Invalid {} '{}'", key, format));
+            }
+        }
+        let sample = NaiveDate::from_ymd_opt(2001, 2, 3)
+            .unwrap()
+            .and_hms_opt(4, 5, 0)
+            .unwrap();
+        let formatted = sample.format(&self.datetime_format).to_string();
+        if NaiveDateTime::parse_from_str(&formatted, &self.datetime_format).ok() != Some(sample) {
+            return Err(anyhow!(
+                "datetime_format '{}' has to contain a full date and time, e.g. '%Y-%m-%d %H:%M'",
+                self.datetime_format
+            ));
+        }
+
+        self.working_hours()?;
+        self.working_days()?;
+        self.week_start()?;
+
+        if !["auto", "always", "never"].contains(&self.color.as_str()) {
+            return Err(anyhow!(
+                "Invalid color '{}', expected auto, always or never",
+                self.color
+            ));
+        }
+
+        Ok(())
     }
 }
 
 impl Default for AppConfig {
+    /// The `data_dir` is left empty when there is no home directory, which
+    /// `database_url` reports unless `DATABASE_URL` is set.
     fn default() -> Self {
-        if let Some(proj_dirs) = ProjectDirs::from("ch", "lethani", "aze") {
-            return AppConfig {
-                data_dir: proj_dirs.data_dir().to_str().unwrap().to_string(),
-                datetime_format: "%Y-%m-%d %H:%M".to_string(),
-            };
+        AppConfig {
+            data_dir: ProjectDirs::from("ch", "lethani", "aze")
+                .map(|proj_dirs| proj_dirs.data_dir().to_string_lossy().to_string())
+                .unwrap_or_default(),
+            datetime_format: "%Y-%m-%d %H:%M".to_string(),
+            date_format: "%a %-d %-m %Y".to_string(),
+            time_format: "%H:%M".to_string(),
+            duration_format: "%Hh %Mm %Ss".to_string(),
+            week_start: "Mon".to_string(),
+            log_range: "7d".to_string(),
+            log_current: false,
+            log_pager: false,
+            color: "auto".to_string(),
+            working_hours_start: "09:00".to_string(),
+            working_hours_end: "17:00".to_string(),
+            working_days: ["Mon", "Tue", "Wed", "Thu", "Fri"]
+                .iter()
+                .map(|day| day.to_string())
+                .collect(),
+            allow_concurrent_frames: false,
+            stop_on_start: false,
+            max_frame_duration: None,
+            pomodoro_work: "25m".to_string(),
+            pomodoro_break: "5m".to_string(),
+            pomodoro_cycles: 4,
         }
+    }
+}
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+pub fn config_dir() -> error::Result<PathBuf> {
+    ProjectDirs::from("rs", "", "aze")
+        .map(|proj_dirs| proj_dirs.config_dir().to_path_buf())
+        .ok_or(Error::HomeDirectory)
+}
+
+/// The profile given by `AZE_PROFILE`, otherwise the one selected with
+/// `aze profile switch`.
+pub fn active_profile() -> error::Result<String> {
+    let profile = match env::var("AZE_PROFILE") {
+        Ok(profile) => Some(profile),
+        Err(_) => fs::read_to_string(config_dir()?.join("profile")).ok(),
+    };
 
-        panic!("Could not evaluate data_dir");
+    let profile = profile
+        .map(|profile| profile.trim().to_string())
+        .filter(|profile| !profile.is_empty())
+        .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+    validate_profile_name(&profile)?;
+
+    Ok(profile)
+}
+
+/// Profile names become file and directory names, so only letters, digits,
+/// `-` and `_` are allowed.
+pub fn validate_profile_name(profile: &str) -> error::Result<()> {
+    if profile.is_empty()
+        || !profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(Error::InvalidProfileName(profile.to_string()));
     }
+
+    Ok(())
+}
+
+pub fn profile_config_path(profile: &str) -> error::Result<PathBuf> {
+    validate_profile_name(profile)?;
+    let config_dir = config_dir()?;
+
+    Ok(if profile == DEFAULT_PROFILE {
+        config_dir.join("aze.toml")
+    } else {
+        config_dir
+            .join("profiles")
+            .join(format!("{}.toml", profile))
+    })
 }
 
-pub fn load_config() -> AppConfig {
-    let cfg: Result<AppConfig, ConfyError> = confy::load("aze");
+pub fn profiles() -> error::Result<Vec<String>> {
+    let mut profiles: Vec<String> = fs::read_dir(config_dir()?.join("profiles"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .and_then(|name| name.strip_suffix(".toml"))
+                        .map(|name| name.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    profiles.sort();
+    profiles.insert(0, DEFAULT_PROFILE.to_string());
 
-    cfg.unwrap_or_default()
+    Ok(profiles)
+}
+
+pub fn config_path() -> error::Result<PathBuf> {
+    profile_config_path(&active_profile()?)
+}
+
+/// Reads the configuration of the active profile without validating it, the
+/// defaults if it has no configuration file yet.
+pub fn read_config() -> error::Result<AppConfig> {
+    read_config_file(&config_path()?)
+}
+
+fn read_config_file(path: &Path) -> error::Result<AppConfig> {
+    let path = path.to_path_buf();
+
+    match fs::read_to_string(&path) {
+        Ok(content) => {
+            toml::from_str(&content).map_err(|source| Error::InvalidFile { path, source })
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(AppConfig::default()),
+        Err(source) => Err(Error::ReadFile { path, source }),
+    }
+}
+
+/// Loads the configuration of the active profile and checks its values, so
+/// that e.g. an invalid date format is reported instead of panicking later.
+pub fn load_config() -> error::Result<AppConfig> {
+    let path = config_path()?;
+    let config = read_config_file(&path)?;
+
+    config.validate().map_err(|error| Error::InvalidConfig {
+        path,
+        message: error.to_string(),
+    })?;
+
+    Ok(config)
 }
 
 #[cfg(test)]
 mod tests {
     use directories_next::ProjectDirs;
 
+    #[test]
+    fn default_config_is_valid() {
+        assert!(super::AppConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn datetime_format_has_to_round_trip() {
+        let config = super::AppConfig {
+            datetime_format: "%H:%M".to_string(),
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn default_config_dir() {
-        let config = super::load_config();
+        let config = super::load_config().unwrap();
 
         if let Some(proj_dirs) = ProjectDirs::from("ch", "lethani", "aze") {
             assert_eq!(
@@ -1,6 +1,8 @@
-use std::error::Error;
+use std::fs::create_dir_all;
+use std::path::Path;
 
 use crate::config::load_config;
+use crate::error::{Error, Result};
 use diesel::backend::{Backend, RawValue};
 use diesel::deserialize::FromSql;
 use diesel::r2d2;
@@ -12,26 +14,38 @@ use diesel::SqliteConnection;
 use diesel::{deserialize, serialize};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 
-pub fn get_connection_pool() -> Pool<ConnectionManager<SqliteConnection>> {
-    let cfg = load_config();
-    let url = cfg.database_url();
+pub fn get_connection_pool() -> Result<Pool<ConnectionManager<SqliteConnection>>> {
+    let url = load_config()?.database_url()?;
 
-    let manager = ConnectionManager::<SqliteConnection>::new(url);
-    r2d2::Pool::new(manager).unwrap()
+    if let Some(path) = Path::new(&url).parent() {
+        if !path.as_os_str().is_empty() && !path.exists() {
+            create_dir_all(path).map_err(|source| Error::DataDirectory {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        }
+    }
+
+    let manager = ConnectionManager::<SqliteConnection>::new(&url);
+    r2d2::Pool::new(manager).map_err(|source| Error::Connection { url, source })
 }
 
-pub fn establish_connection() -> PooledConnection<ConnectionManager<SqliteConnection>> {
-    let pool = get_connection_pool();
+pub fn establish_connection() -> Result<PooledConnection<ConnectionManager<SqliteConnection>>> {
+    let url = load_config()?.database_url()?;
+    let pool = get_connection_pool()?;
 
-    pool.get().unwrap()
+    pool.get()
+        .map_err(|source| Error::Connection { url, source })
 }
 
 pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
 
 pub fn run_migrations(
     connection: &mut impl MigrationHarness<diesel::sqlite::Sqlite>,
-) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
-    connection.run_pending_migrations(MIGRATIONS)?;
+) -> Result<()> {
+    connection
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|error| Error::Migration(error.to_string()))?;
 
     Ok(())
 }
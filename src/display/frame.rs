@@ -3,12 +3,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::Frame;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct JsonFrame {
     pub start: NaiveDateTime,
     pub end: Option<NaiveDateTime>,
     pub project: String,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub note: Option<String>,
 }
 
 impl JsonFrame {
@@ -18,6 +20,23 @@ impl JsonFrame {
             end: frame.end,
             project: frame.to_owned().project,
             tags: frame.tags.values(),
+            note: frame.note.to_owned(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct JsonFrameWithId {
+    pub id: String,
+    #[serde(flatten)]
+    pub frame: JsonFrame,
+}
+
+impl JsonFrameWithId {
+    pub fn new(frame: &Frame) -> Self {
+        Self {
+            id: frame.id.to_string(),
+            frame: JsonFrame::new(frame),
         }
     }
 }
@@ -5,6 +5,8 @@ use chrono::{Duration, Local, NaiveDate};
 use crate::models::Frame;
 
 pub mod frame;
+pub mod project;
+pub mod status;
 
 #[derive(Clone)]
 pub struct Display {
@@ -29,8 +31,8 @@ impl Display {
         let mut duration = Duration::zero();
         let frames = self.frames.clone();
         for frame in frames {
-            if frame.end.is_some() {
-                duration = duration.add(frame.end.unwrap() - frame.start);
+            if let Some(end) = frame.end {
+                duration = duration.add(end - frame.start);
             } else {
                 let now = Local::now().naive_local();
                 duration = duration.add(now - frame.start)
@@ -60,6 +62,7 @@ mod tests {
             project: "1".to_string(),
             tags: MyJsonType(json!({})),
             deleted: false,
+            note: None,
         };
 
         let frame2 = Frame {
@@ -70,10 +73,11 @@ mod tests {
             project: "1".to_string(),
             tags: MyJsonType(json!({})),
             deleted: false,
+            note: None,
         };
 
         let display = Display {
-            date: Local::now().date().naive_local(),
+            date: Local::now().date_naive(),
             frames: vec![frame1, frame2],
         };
 
@@ -83,8 +87,14 @@ mod tests {
     #[test]
     fn duration_from_multiple_frames() {
         use serde_json::json;
-        let start: NaiveDateTime = NaiveDate::from_ymd(2001, 1, 1).and_hms(10, 0, 0);
-        let end: NaiveDateTime = NaiveDate::from_ymd(2001, 1, 1).and_hms(11, 0, 0);
+        let start: NaiveDateTime = NaiveDate::from_ymd_opt(2001, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap();
+        let end: NaiveDateTime = NaiveDate::from_ymd_opt(2001, 1, 1)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap();
         let frame1 = Frame {
             id: "1".to_string(),
             start,
@@ -93,6 +103,7 @@ mod tests {
             project: "1".to_string(),
             tags: MyJsonType(json!({})),
             deleted: false,
+            note: None,
         };
 
         let frame2 = Frame {
@@ -103,10 +114,11 @@ mod tests {
             project: "1".to_string(),
             tags: MyJsonType(json!({})),
             deleted: false,
+            note: None,
         };
 
         let mut display = Display {
-            date: Local::now().date().naive_local(),
+            date: Local::now().date_naive(),
             frames: vec![frame1, frame2],
         };
 
@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use chrono::Duration;
+
+use crate::service::project::SEPARATOR;
+
+#[derive(Debug)]
+pub struct ProjectTree {
+    pub children: BTreeMap<String, ProjectTree>,
+    pub duration: Duration,
+}
+
+impl Default for ProjectTree {
+    fn default() -> Self {
+        Self {
+            children: BTreeMap::new(),
+            duration: Duration::zero(),
+        }
+    }
+}
+
+impl ProjectTree {
+    pub fn new(projects: Vec<String>) -> Self {
+        let mut tree = Self::default();
+        for project in projects {
+            tree.insert(&project);
+        }
+        tree
+    }
+
+    pub fn insert(&mut self, project: &str) {
+        self.add_duration(project, Duration::zero());
+    }
+
+    /// Adds the duration to the project and every parent project above it.
+    pub fn add_duration(&mut self, project: &str, duration: Duration) {
+        let mut node = self;
+        for part in project.split(SEPARATOR).filter(|part| !part.is_empty()) {
+            node = node.children.entry(part.to_string()).or_default();
+            node.duration = node.duration + duration;
+        }
+    }
+
+    pub fn lines(&self) -> Vec<(String, String, Duration)> {
+        let mut lines = vec![];
+        for (name, child) in &self.children {
+            lines.push((String::new(), name.to_string(), child.duration));
+            child.child_lines("", &mut lines);
+        }
+        lines
+    }
+
+    fn child_lines(&self, prefix: &str, lines: &mut Vec<(String, String, Duration)>) {
+        let count = self.children.len();
+        for (index, (name, child)) in self.children.iter().enumerate() {
+            let last = index + 1 == count;
+            lines.push((
+                format!("{}{}", prefix, if last { "└── " } else { "├── " }),
+                name.to_string(),
+                child.duration,
+            ));
+            child.child_lines(
+                &format!("{}{}", prefix, if last { "    " } else { "│   " }),
+                lines,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::ProjectTree;
+
+    #[test]
+    fn nested_projects_are_grouped() {
+        let tree = ProjectTree::new(vec![
+            "client/project/task".to_string(),
+            "client/other".to_string(),
+            "single".to_string(),
+        ]);
+
+        assert_eq!(2, tree.children.len());
+        assert_eq!(2, tree.children["client"].children.len());
+        assert!(tree.children["client"].children["project"]
+            .children
+            .contains_key("task"));
+    }
+
+    #[test]
+    fn lines_are_indented() {
+        let tree = ProjectTree::new(vec![
+            "client/project/task".to_string(),
+            "client/other".to_string(),
+        ]);
+
+        let lines: Vec<String> = tree
+            .lines()
+            .iter()
+            .map(|(prefix, name, _)| format!("{}{}", prefix, name))
+            .collect();
+
+        assert_eq!(
+            vec!["client", "├── other", "└── project", "    └── task"],
+            lines
+        );
+    }
+
+    #[test]
+    fn durations_roll_up_to_parents() {
+        let mut tree = ProjectTree::default();
+        tree.add_duration("client/project/task", Duration::hours(1));
+        tree.add_duration("client/project", Duration::hours(2));
+        tree.add_duration("client/other", Duration::minutes(30));
+
+        let client = &tree.children["client"];
+        assert_eq!(Duration::minutes(210), client.duration);
+        assert_eq!(Duration::hours(3), client.children["project"].duration);
+        assert_eq!(
+            Duration::hours(1),
+            client.children["project"].children["task"].duration
+        );
+    }
+}
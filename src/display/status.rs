@@ -0,0 +1,236 @@
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDateTime;
+use regex::{Captures, Regex};
+use serde::Serialize;
+
+use crate::display::frame::JsonFrameWithId;
+use crate::format_clock;
+use crate::models::Frame;
+
+#[derive(Serialize, Debug)]
+pub struct JsonStatus {
+    #[serde(flatten)]
+    pub frame: JsonFrameWithId,
+    pub elapsed: i64,
+}
+
+impl JsonStatus {
+    pub fn new(frame: &Frame, now: &NaiveDateTime) -> Self {
+        Self {
+            frame: JsonFrameWithId::new(frame),
+            elapsed: (*now - frame.start).num_seconds(),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bar {
+    Waybar,
+    I3blocks,
+    Tmux,
+}
+
+#[derive(Serialize, Debug)]
+struct WaybarStatus {
+    text: String,
+    tooltip: String,
+    class: String,
+}
+
+const RUNNING_COLOR: &str = "#98c379";
+const IDLE_COLOR: &str = "#808080";
+
+/// Renders the running frame, if any, in the protocol expected by the given
+/// status bar.
+pub fn render_bar(
+    bar: Bar,
+    frame: Option<&Frame>,
+    now: &NaiveDateTime,
+    datetime_format: &str,
+) -> String {
+    let (text, tooltip) = match frame {
+        Some(frame) => (
+            format!("{} {}", frame.project, format_clock(&(*now - frame.start))),
+            format!(
+                "Project {}{} started {}",
+                frame.project,
+                if frame.tags.values().is_empty() {
+                    "".to_string()
+                } else {
+                    format!(" [{}]", frame.tags.values().join(", "))
+                },
+                frame.start.format(datetime_format)
+            ),
+        ),
+        None => ("idle".to_string(), "No project started.".to_string()),
+    };
+
+    match bar {
+        Bar::Waybar => serde_json::to_string(&WaybarStatus {
+            text,
+            tooltip,
+            class: if frame.is_some() { "running" } else { "idle" }.to_string(),
+        })
+        .unwrap(),
+        Bar::I3blocks => format!(
+            "{}\n{}\n{}",
+            text,
+            frame.map_or("idle", |frame| &frame.project),
+            if frame.is_some() {
+                RUNNING_COLOR
+            } else {
+                IDLE_COLOR
+            }
+        ),
+        Bar::Tmux => format!(
+            "#[fg={}]{}#[default]",
+            if frame.is_some() {
+                RUNNING_COLOR
+            } else {
+                IDLE_COLOR
+            },
+            text.replace('#', "##")
+        ),
+    }
+}
+
+/// Replaces the placeholders `{project}`, `{tags}`, `{start}`, `{elapsed}`,
+/// `{note}` and `{id}` of the template with the values of the frame.
+///
+/// `{start:FORMAT}` takes a strftime format and `{elapsed:UNIT}` one of `s`,
+/// `m` or `h`; `{elapsed}` alone is rendered as `HH:MM:SS`.
+pub fn render_status(
+    template: &str,
+    frame: &Frame,
+    now: &NaiveDateTime,
+    datetime_format: &str,
+) -> Result<String, String> {
+    let placeholder = Regex::new(r"\{(\w+)(?::([^}]*))?\}").unwrap();
+    let elapsed = *now - frame.start;
+    let mut error = None;
+
+    let rendered = placeholder.replace_all(template, |captures: &Captures| {
+        let option = captures.get(2).map(|value| value.as_str());
+
+        match (&captures[1], option) {
+            ("project", None) => frame.project.to_string(),
+            ("tags", None) => frame.tags.values().join(", "),
+            ("note", None) => frame.note.to_owned().unwrap_or_default(),
+            ("id", None) => frame.id[..7].to_string(),
+            ("start", format) => {
+                let format = format.unwrap_or(datetime_format);
+                if StrftimeItems::new(format).any(|item| item == Item::Error) {
+                    error.get_or_insert_with(|| format!("Invalid date format in {}", &captures[0]));
+                    return String::new();
+                }
+                frame.start.format(format).to_string()
+            }
+            ("elapsed", None) => format_clock(&elapsed),
+            ("elapsed", Some("s")) => elapsed.num_seconds().to_string(),
+            ("elapsed", Some("m")) => elapsed.num_minutes().to_string(),
+            ("elapsed", Some("h")) => format!("{:.2}", elapsed.num_seconds() as f64 / 3600.0),
+            _ => {
+                error.get_or_insert_with(|| format!("Unknown placeholder {}", &captures[0]));
+                String::new()
+            }
+        }
+    });
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(rendered.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+    use serde_json::json;
+
+    use super::{render_bar, render_status, Bar};
+    use crate::database::MyJsonType;
+    use crate::models::Frame;
+
+    fn at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn frame() -> Frame {
+        Frame {
+            id: "abcdef0123".to_string(),
+            start: at(10, 0),
+            end: None,
+            last_update: at(10, 0),
+            project: "apollo11".to_string(),
+            tags: MyJsonType(json!(["brakes", "module"])),
+            deleted: false,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn render_placeholders() {
+        assert_eq!(
+            Ok("apollo11 [brakes, module] 01:30:00".to_string()),
+            render_status(
+                "{project} [{tags}] {elapsed}",
+                &frame(),
+                &at(11, 30),
+                "%H:%M"
+            )
+        );
+    }
+
+    #[test]
+    fn render_elapsed_units_and_start_format() {
+        assert_eq!(
+            Ok("90 1.50 10h00".to_string()),
+            render_status(
+                "{elapsed:m} {elapsed:h} {start:%Hh%M}",
+                &frame(),
+                &at(11, 30),
+                "%H:%M"
+            )
+        );
+    }
+
+    #[test]
+    fn render_waybar() {
+        assert_eq!(
+            r#"{"text":"apollo11 01:30:00","tooltip":"Project apollo11 [brakes, module] started 10:00","class":"running"}"#,
+            render_bar(Bar::Waybar, Some(&frame()), &at(11, 30), "%H:%M")
+        );
+        assert!(render_bar(Bar::Waybar, None, &at(11, 30), "%H:%M").contains(r#""class":"idle""#));
+    }
+
+    #[test]
+    fn render_i3blocks_and_tmux() {
+        assert_eq!(
+            "apollo11 01:30:00\napollo11\n#98c379",
+            render_bar(Bar::I3blocks, Some(&frame()), &at(11, 30), "%H:%M")
+        );
+        assert_eq!(
+            "#[fg=#808080]idle#[default]",
+            render_bar(Bar::Tmux, None, &at(11, 30), "%H:%M")
+        );
+    }
+
+    #[test]
+    fn unknown_placeholder_is_an_error() {
+        assert_eq!(
+            Err("Unknown placeholder {duration}".to_string()),
+            render_status("{duration}", &frame(), &at(11, 30), "%H:%M")
+        );
+    }
+
+    #[test]
+    fn invalid_start_format_is_an_error() {
+        assert_eq!(
+            Err("Invalid date format in {start:%Q}".to_string()),
+            render_status("{start:%Q}", &frame(), &at(11, 30), "%H:%M")
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::io;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Could not find the home directory to store the configuration and data in")]
+    HomeDirectory,
+
+    #[error("Could not create the data directory {}", path.display())]
+    DataDirectory { path: PathBuf, source: io::Error },
+
+    #[error("Could not open the database {url}")]
+    Connection {
+        url: String,
+        source: diesel::r2d2::PoolError,
+    },
+
+    #[error("Could not update the database: {0}")]
+    Migration(String),
+
+    #[error("Database query failed")]
+    Query(#[from] diesel::result::Error),
+
+    #[error("No frame found with id {0}.")]
+    FrameNotFound(String),
+
+    /// No frame is running, reported by the exit code alone.
+    #[error("No project started.")]
+    NoRunningFrame,
+
+    #[error("No project started.")]
+    NothingToStop,
+
+    #[error("No running frame matches {0}.")]
+    NoMatchingFrame(String),
+
+    #[error("Several frames are running, specify which one to stop: {0}")]
+    SeveralRunningFrames(String),
+
+    #[error("Unknown profile '{0}'. Create it with 'aze profile create {0}'.")]
+    ProfileNotFound(String),
+
+    #[error("Invalid profile name '{0}', only letters, digits, '-' and '_' are allowed")]
+    InvalidProfileName(String),
+
+    #[error("Invalid tags {tags} in the database, run 'aze doctor' to fix them")]
+    InvalidTags {
+        tags: String,
+        source: serde_json::Error,
+    },
+
+    #[error("Could not read {}", path.display())]
+    ReadFile { path: PathBuf, source: io::Error },
+
+    #[error("Invalid {}", path.display())]
+    InvalidFile {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+
+    #[error("Invalid {}: {message}", path.display())]
+    InvalidConfig { path: PathBuf, message: String },
+
+    #[error("Could not read the answer to the prompt")]
+    Prompt(#[source] io::Error),
+}
+
+/// Exit codes of aze, besides 0 on success and 2 for invalid arguments.
+pub const EXIT_CODES: [(i32, &str); 6] = [
+    (1, "the command failed"),
+    (3, "the given frame or profile doesn't exist"),
+    (4, "the database couldn't be opened, updated or queried"),
+    (5, "a file or the stored data is invalid"),
+    (
+        6,
+        "no frame is running, for 'aze stop' and for 'aze status --format' or '--json'",
+    ),
+    (7, "several frames are running and none was selected"),
+];
+
+impl Error {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Prompt(_) | Error::HomeDirectory => 1,
+            Error::FrameNotFound(_)
+            | Error::NoMatchingFrame(_)
+            | Error::ProfileNotFound(_)
+            | Error::InvalidProfileName(_) => 3,
+            Error::DataDirectory { .. }
+            | Error::Connection { .. }
+            | Error::Migration(_)
+            | Error::Query(_) => 4,
+            Error::InvalidTags { .. }
+            | Error::ReadFile { .. }
+            | Error::InvalidFile { .. }
+            | Error::InvalidConfig { .. } => 5,
+            Error::NoRunningFrame | Error::NothingToStop => 6,
+            Error::SeveralRunningFrames(_) => 7,
+        }
+    }
+
+    /// Whether the exit code alone reports the error, without a message.
+    pub fn is_quiet(&self) -> bool {
+        matches!(self, Error::NoRunningFrame)
+    }
+}
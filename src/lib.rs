@@ -1,12 +1,13 @@
 pub mod schema;
 
 pub mod models;
-use chrono::{Local, NaiveDateTime};
+use chrono::{Duration, Local, NaiveDateTime};
 
 pub mod cli;
 pub mod config;
 pub mod database;
 pub mod display;
+pub mod error;
 pub mod service;
 
 #[macro_use]
@@ -29,3 +30,46 @@ pub fn ago(ago: NaiveDateTime) -> String {
         "just now".to_string()
     }
 }
+
+/// Formats a duration with `%H` for the hours, `%M` for the minutes and `%S`
+/// for the seconds, the latter two zero padded. `%%` is a literal percent sign.
+pub fn format_duration_with(duration: &Duration, format: &str) -> String {
+    let mut result = String::new();
+    let mut chars = format.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('H') => result.push_str(&duration.num_hours().to_string()),
+            Some('M') => result.push_str(&format!(
+                "{:02}",
+                duration.num_minutes() - (duration.num_hours() * 60)
+            )),
+            Some('S') => result.push_str(&format!(
+                "{:02}",
+                duration.num_seconds() - (duration.num_minutes() * 60)
+            )),
+            Some('%') => result.push('%'),
+            Some(other) => {
+                result.push('%');
+                result.push(other);
+            }
+            None => result.push('%'),
+        }
+    }
+
+    result
+}
+
+pub fn format_clock(duration: &Duration) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        duration.num_hours(),
+        duration.num_minutes() - (duration.num_hours() * 60),
+        duration.num_seconds() - (duration.num_minutes() * 60)
+    )
+}
@@ -2,7 +2,7 @@ extern crate diesel;
 
 use clap::Parser;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use commands::add::AddSubcommand;
 use commands::edit::EditSubcommand;
 use commands::log::LogSubcommand;
@@ -12,13 +12,26 @@ use commands::status::StatusSubcommand;
 use commands::stop::StopSubcommand;
 use commands::projects::ProjectsSubcommand;
 use commands::remove::RemoveSubcommand;
-use commands::{MyCommand, Output};
+use commands::search::SearchSubcommand;
+use commands::split::SplitSubcommand;
+use commands::join::JoinSubcommand;
+use commands::shift::ShiftSubcommand;
+use commands::gaps::GapsSubcommand;
+use commands::help::HelpSubcommand;
+use commands::man::ManSubcommand;
+use commands::doctor::DoctorSubcommand;
+use commands::fill::FillSubcommand;
+use commands::pomodoro::PomodoroSubcommand;
+use commands::completions::{CompleteSubcommand, CompletionsSubcommand};
+use commands::config::ConfigSubcommand;
+use commands::profile::{ensure_profile_exists, ProfileSubcommand};
+use commands::{ColorChoice, MyCommand, Output};
+use aze::config;
 use aze::database::{establish_connection, run_migrations};
 pub mod commands;
-mod config;
 
 #[derive(Debug, Parser)]
-#[clap(name = "aze")]
+#[clap(name = "aze", disable_help_subcommand = true)]
 #[clap(
     about = "aze is a tool aimed at helping you monitoring your time.\n\nYou just have to tell aze when you start working on your project with the `start` command, and you can stop the timer when you're done with the `stop` command."
 )]
@@ -35,7 +48,7 @@ struct Cli {
     #[clap(
         global = true,
         long = "color",
-        help = "Color output",
+        help = "Color output, even if it is not written to a terminal.",
         conflicts_with = "no-color",
         display_order = 9998
     )]
@@ -44,12 +57,20 @@ struct Cli {
     #[clap(
         global = true,
         long = "no-color",
-        help = "Don't color output",
+        help = "Don't color output. Colors are also disabled by the NO_COLOR environment variable.",
         conflicts_with = "color",
         display_order = 9997
     )]
     no_color: bool,
 
+    #[clap(
+        global = true,
+        long = "profile",
+        help = "Use the configuration and frames of the given profile.",
+        display_order = 9996
+    )]
+    profile: Option<String>,
+
     #[clap(subcommand)]
     command: Commands,
 }
@@ -64,26 +85,87 @@ enum Commands {
     Edit(EditSubcommand),
     Frames(FramesSubcommand),
     Projects(ProjectsSubcommand),
-    Remove(RemoveSubcommand)
+    Remove(RemoveSubcommand),
+    Search(SearchSubcommand),
+    Split(SplitSubcommand),
+    Join(JoinSubcommand),
+    Shift(ShiftSubcommand),
+    Gaps(GapsSubcommand),
+    Fill(FillSubcommand),
+    Doctor(DoctorSubcommand),
+    Pomodoro(PomodoroSubcommand),
+    Config(ConfigSubcommand),
+    Profile(ProfileSubcommand),
+    Completions(CompletionsSubcommand),
+    Help(HelpSubcommand),
+    Man(ManSubcommand),
+    #[clap(name = "__complete", hide = true)]
+    Complete(CompleteSubcommand),
 }
 
-fn main() -> Result<()> {
-    let mut conn = establish_connection();
-    let migrations = run_migrations(&mut conn);
-    if migrations.is_err() {
-        return Err(anyhow!("Could not update internal database"));
+fn main() {
+    if let Err(error) = run() {
+        let cause = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<aze::error::Error>());
+        if !cause.is_some_and(|cause| cause.is_quiet()) {
+            eprintln!("Error: {:?}", error);
+        }
+
+        std::process::exit(cause.map_or(1, |cause| cause.exit_code()));
     }
+}
 
+fn run() -> Result<()> {
     let args = Cli::parse();
+
+    if let Some(profile) = &args.profile {
+        std::env::set_var("AZE_PROFILE", profile);
+    }
+    if !matches!(args.command, Commands::Profile(_)) {
+        ensure_profile_exists(&config::active_profile()?)?;
+    }
+
+    // 'aze config' has to work with an invalid configuration to repair it,
+    // and doesn't need the database.
+    let repairing = matches!(args.command, Commands::Config(_));
+    let color_setting = match config::load_config() {
+        Ok(config) => config.color,
+        Err(_) if repairing => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+
+    // The connection is kept open while the command runs, an in-memory
+    // database would be dropped with it.
+    let _conn = if repairing {
+        None
+    } else {
+        let mut conn = establish_connection()?;
+        run_migrations(&mut conn)?;
+        Some(conn)
+    };
+
+    let color = ColorChoice::new(args.color, args.no_color, &color_setting);
+    let mut stdout = std::io::stdout();
+    let output = Output::new(&mut stdout, color.enabled());
+
     if args.version_flag {
         println!("{}", env!("CARGO_PKG_VERSION"));
         return Ok(());
     }
 
-    let output: Output = Output {
-        out: &mut std::io::stdout(),
-    };
-
+    if !matches!(
+        args.command,
+        Commands::Stop(_)
+            | Commands::Status(_)
+            | Commands::Config(_)
+            | Commands::Completions(_)
+            | Commands::Complete(_)
+            | Commands::Help(_)
+            | Commands::Man(_)
+    ) {
+        commands::prompt_idle_frames()?;
+    }
 
     match args.command {
         Commands::Add(command) => command.run(output),
@@ -95,5 +177,19 @@ fn main() -> Result<()> {
         Commands::Frames(command) => command.run(output),
         Commands::Projects(command) => command.run(output),
         Commands::Remove(command) => command.run(output),
+        Commands::Search(command) => command.run(output),
+        Commands::Split(command) => command.run(output),
+        Commands::Join(command) => command.run(output),
+        Commands::Shift(command) => command.run(output),
+        Commands::Gaps(command) => command.run(output),
+        Commands::Fill(command) => command.run(output),
+        Commands::Doctor(command) => command.run(output),
+        Commands::Pomodoro(command) => command.run(output),
+        Commands::Config(command) => command.run(output),
+        Commands::Profile(command) => command.run(output),
+        Commands::Completions(command) => command.run(output),
+        Commands::Help(command) => command.run(output),
+        Commands::Man(command) => command.run(output),
+        Commands::Complete(command) => command.run(output),
     }
 }
@@ -12,6 +12,7 @@ pub struct Frame {
     pub project: String,
     pub tags: MyJsonType,
     pub deleted: bool,
+    pub note: Option<String>,
 }
 
 #[derive(Insertable)]
@@ -24,4 +25,5 @@ pub struct NewFrame<'a> {
     pub project: &'a str,
     pub tags: &'a MyJsonType,
     pub deleted: &'a bool,
+    pub note: Option<&'a str>,
 }
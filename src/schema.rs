@@ -7,5 +7,6 @@ table! {
         project -> Text,
         tags -> Text,
         deleted -> Bool,
+        note -> Nullable<Text>,
     }
 }
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+
+pub const DEFAULTS_FILE: &str = ".aze.toml";
+
+/// Project defaults for a directory tree, read from the nearest `.aze.toml`.
+#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct DirectoryDefaults {
+    pub project: Option<String>,
+    pub tags: Vec<String>,
+}
+
+pub fn find_defaults_file(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|ancestor| ancestor.join(DEFAULTS_FILE))
+        .find(|path| path.is_file())
+}
+
+pub fn load_defaults(dir: &Path) -> Result<DirectoryDefaults> {
+    let path = match find_defaults_file(dir) {
+        Some(path) => path,
+        None => return Ok(DirectoryDefaults::default()),
+    };
+
+    let content = fs::read_to_string(&path).map_err(|source| Error::ReadFile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    toml::from_str(&content).map_err(|source| Error::InvalidFile { path, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{load_defaults, DirectoryDefaults, DEFAULTS_FILE};
+
+    #[test]
+    fn defaults_are_found_in_parent_directories() {
+        let root = tempfile::tempdir().unwrap();
+        let nested = root.path().join("src").join("bin");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(
+            root.path().join(DEFAULTS_FILE),
+            "project = \"apollo11\"\ntags = [\"brakes\"]",
+        )
+        .unwrap();
+
+        assert_eq!(
+            DirectoryDefaults {
+                project: Some("apollo11".to_string()),
+                tags: vec!["brakes".to_string()],
+            },
+            load_defaults(&nested).unwrap()
+        );
+    }
+
+    #[test]
+    fn nearest_defaults_file_wins() {
+        let root = tempfile::tempdir().unwrap();
+        let nested = root.path().join("module");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.path().join(DEFAULTS_FILE), "project = \"outer\"").unwrap();
+        fs::write(nested.join(DEFAULTS_FILE), "project = \"inner\"").unwrap();
+
+        assert_eq!(
+            Some("inner".to_string()),
+            load_defaults(&nested).unwrap().project
+        );
+    }
+
+    #[test]
+    fn invalid_defaults_file_is_an_error() {
+        let root = tempfile::tempdir().unwrap();
+        fs::write(root.path().join(DEFAULTS_FILE), "project = [").unwrap();
+
+        assert!(load_defaults(root.path()).is_err());
+    }
+}
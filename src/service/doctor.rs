@@ -0,0 +1,376 @@
+use chrono::{Local, NaiveDateTime};
+use diesel::prelude::*;
+
+use crate::database::{establish_connection, MyJsonType};
+use crate::error::Result;
+use crate::schema::frames;
+
+#[derive(QueryableByName, Debug, Clone)]
+#[diesel(table_name = frames)]
+pub struct RawFrame {
+    pub id: String,
+    pub start: NaiveDateTime,
+    pub end: Option<NaiveDateTime>,
+    pub last_update: NaiveDateTime,
+    pub project: String,
+    #[diesel(sql_type = diesel::sql_types::Text)]
+    pub tags: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fix {
+    ResetTags { id: String, tags: Vec<String> },
+    SetLastUpdate { id: String, value: NaiveDateTime },
+    SwapStartEnd { id: String },
+    Stop { id: String, at: NaiveDateTime },
+    Delete { id: String },
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    pub description: String,
+    pub fixes: Vec<Fix>,
+}
+
+impl Fix {
+    pub fn description(&self, datetime_format: &str) -> String {
+        match self {
+            Fix::ResetTags { id, tags } => {
+                format!("Set tags of frame {} to [{}]", &id[..7], tags.join(", "))
+            }
+            Fix::SetLastUpdate { id, value } => format!(
+                "Set last update of frame {} to {}",
+                &id[..7],
+                value.format(datetime_format)
+            ),
+            Fix::SwapStartEnd { id } => format!("Swap start and end of frame {}", &id[..7]),
+            Fix::Stop { id, at } => {
+                format!("End frame {} at {}", &id[..7], at.format(datetime_format))
+            }
+            Fix::Delete { id } => format!("Remove frame {}", &id[..7]),
+        }
+    }
+
+    pub fn apply(&self) -> Result<()> {
+        use crate::schema::frames::dsl;
+        let mut conn = establish_connection()?;
+
+        match self {
+            Fix::ResetTags { id, tags } => diesel::update(dsl::frames.find(id))
+                .set(dsl::tags.eq(MyJsonType(serde_json::json!(tags))))
+                .execute(&mut conn),
+            Fix::SetLastUpdate { id, value } => diesel::update(dsl::frames.find(id))
+                .set(dsl::last_update.eq(value))
+                .execute(&mut conn),
+            Fix::SwapStartEnd { id } => diesel::update(dsl::frames.find(id))
+                .set((
+                    dsl::start.eq(dsl::end.assume_not_null()),
+                    dsl::end.eq(dsl::start.nullable()),
+                ))
+                .execute(&mut conn),
+            Fix::Stop { id, at } => diesel::update(dsl::frames.find(id))
+                .set(dsl::end.eq(at))
+                .execute(&mut conn),
+            Fix::Delete { id } => diesel::update(dsl::frames.find(id))
+                .set(dsl::deleted.eq(true))
+                .execute(&mut conn),
+        }?;
+
+        Ok(())
+    }
+}
+
+fn describe(frame: &RawFrame, datetime_format: &str) -> String {
+    format!(
+        "{} ({}, {} to {})",
+        &frame.id[..7],
+        frame.project,
+        frame.start.format(datetime_format),
+        frame.end.map_or("now".to_string(), |end| end
+            .format(datetime_format)
+            .to_string())
+    )
+}
+
+fn parse_tags(tags: &str) -> Option<Vec<String>> {
+    serde_json::from_str::<Vec<String>>(tags).ok()
+}
+
+fn salvage_tags(tags: &str) -> Vec<String> {
+    match serde_json::from_str::<serde_json::Value>(tags) {
+        Ok(value) => MyJsonType(value).values(),
+        Err(_) => vec![],
+    }
+}
+
+pub fn load_raw_frames() -> Result<Vec<RawFrame>> {
+    let mut conn = establish_connection()?;
+
+    let raw_frames = diesel::sql_query(
+        r#"SELECT id, start, end, last_update, project, tags FROM frames WHERE deleted = 0 ORDER BY start"#,
+    )
+    .load::<RawFrame>(&mut conn)?;
+
+    Ok(raw_frames)
+}
+
+pub fn diagnose(
+    raw_frames: &[RawFrame],
+    allow_concurrent_frames: bool,
+    datetime_format: &str,
+) -> Vec<Problem> {
+    let mut problems = vec![];
+    let epoch = NaiveDateTime::default();
+
+    for frame in raw_frames {
+        if parse_tags(&frame.tags).is_none() {
+            problems.push(Problem {
+                description: format!(
+                    "Frame {} has invalid tags: {}",
+                    describe(frame, datetime_format),
+                    frame.tags
+                ),
+                fixes: vec![Fix::ResetTags {
+                    id: frame.id.to_string(),
+                    tags: salvage_tags(&frame.tags),
+                }],
+            });
+        }
+
+        if frame.last_update == epoch {
+            problems.push(Problem {
+                description: format!(
+                    "Frame {} has no last update time",
+                    describe(frame, datetime_format)
+                ),
+                fixes: vec![Fix::SetLastUpdate {
+                    id: frame.id.to_string(),
+                    value: frame.end.unwrap_or(frame.start),
+                }],
+            });
+        }
+
+        if frame.end.is_some_and(|end| end < frame.start) {
+            problems.push(Problem {
+                description: format!(
+                    "Frame {} ends before it starts",
+                    describe(frame, datetime_format)
+                ),
+                fixes: vec![
+                    Fix::SwapStartEnd {
+                        id: frame.id.to_string(),
+                    },
+                    Fix::Delete {
+                        id: frame.id.to_string(),
+                    },
+                ],
+            });
+        }
+    }
+
+    // With concurrent frames allowed, only frames of the same project
+    // conflict with each other.
+    let conflicting =
+        |a: &RawFrame, b: &RawFrame| !allow_concurrent_frames || a.project == b.project;
+
+    let running: Vec<&RawFrame> = raw_frames
+        .iter()
+        .filter(|frame| frame.end.is_none())
+        .collect();
+    for (index, frame) in running.iter().enumerate() {
+        let next = running[index + 1..]
+            .iter()
+            .find(|next| conflicting(frame, next));
+
+        if let Some(next) = next {
+            problems.push(Problem {
+                description: format!(
+                    "Frames {} and {} are both running",
+                    describe(frame, datetime_format),
+                    describe(next, datetime_format)
+                ),
+                fixes: vec![
+                    Fix::Stop {
+                        id: frame.id.to_string(),
+                        at: next.start,
+                    },
+                    Fix::Delete {
+                        id: frame.id.to_string(),
+                    },
+                    Fix::Delete {
+                        id: next.id.to_string(),
+                    },
+                ],
+            });
+        }
+    }
+
+    let now = Local::now().naive_local();
+    let mut valid: Vec<&RawFrame> = raw_frames
+        .iter()
+        .filter(|frame| frame.end.is_none_or(|end| end >= frame.start))
+        .collect();
+    valid.sort_by_key(|frame| frame.start);
+
+    // Each frame gets at most one problem, ending it at the start of the
+    // first frame overlapping it, so that applying all the suggested fixes
+    // of one diagnosis doesn't end a frame several times.
+    for (index, frame) in valid.iter().enumerate() {
+        let end = frame.end.unwrap_or(now);
+        let next = valid[index + 1..]
+            .iter()
+            .take_while(|next| next.start < end)
+            .filter(|next| conflicting(frame, next))
+            .find(|next| frame.end.is_some() || next.end.is_some());
+
+        if let Some(next) = next {
+            problems.push(Problem {
+                description: format!(
+                    "Frames {} and {} overlap",
+                    describe(frame, datetime_format),
+                    describe(next, datetime_format)
+                ),
+                fixes: vec![
+                    Fix::Stop {
+                        id: frame.id.to_string(),
+                        at: next.start,
+                    },
+                    Fix::Delete {
+                        id: frame.id.to_string(),
+                    },
+                    Fix::Delete {
+                        id: next.id.to_string(),
+                    },
+                ],
+            });
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::{diagnose, Fix, RawFrame};
+
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn frame(id: &str, start: u32, end: Option<u32>) -> RawFrame {
+        RawFrame {
+            id: format!("{}000000", id),
+            start: at(start),
+            end: end.map(at),
+            last_update: at(start),
+            project: "test".to_string(),
+            tags: "[]".to_string(),
+        }
+    }
+
+    #[test]
+    fn healthy_frames_have_no_problems() {
+        let frames = vec![frame("a", 10, Some(11)), frame("b", 11, Some(12))];
+
+        assert!(diagnose(&frames, false, "%H:%M").is_empty());
+    }
+
+    #[test]
+    fn overlapping_frames_are_trimmed() {
+        let frames = vec![frame("a", 10, Some(12)), frame("b", 11, Some(13))];
+
+        let problems = diagnose(&frames, false, "%H:%M");
+        assert_eq!(1, problems.len());
+        assert_eq!(
+            Fix::Stop {
+                id: "a000000".to_string(),
+                at: at(11)
+            },
+            problems[0].fixes[0]
+        );
+    }
+
+    #[test]
+    fn overlapping_frames_are_stopped_once() {
+        let frames = vec![
+            frame("a", 10, Some(14)),
+            frame("b", 11, Some(12)),
+            frame("c", 13, Some(15)),
+        ];
+
+        let problems = diagnose(&frames, false, "%H:%M");
+        assert_eq!(1, problems.len());
+        assert_eq!(
+            Fix::Stop {
+                id: "a000000".to_string(),
+                at: at(11)
+            },
+            problems[0].fixes[0]
+        );
+    }
+
+    #[test]
+    fn invalid_frames_are_detected() {
+        let mut invalid_tags = frame("a", 8, Some(9));
+        invalid_tags.tags = "null".to_string();
+        let mut no_update = frame("b", 10, Some(11));
+        no_update.last_update = NaiveDateTime::default();
+        let reversed = frame("c", 13, Some(12));
+
+        assert_eq!(
+            3,
+            diagnose(&[invalid_tags, no_update, reversed], false, "%H:%M").len()
+        );
+    }
+
+    #[test]
+    fn multiple_running_frames_are_detected() {
+        let frames = vec![frame("a", 10, None), frame("b", 11, None)];
+
+        let problems = diagnose(&frames, false, "%H:%M");
+        assert_eq!(1, problems.len());
+        assert!(problems[0].description.contains("both running"));
+    }
+
+    #[test]
+    fn concurrent_frames_are_allowed_when_configured() {
+        let frames = vec![
+            frame("a", 10, None),
+            RawFrame {
+                project: "other".to_string(),
+                ..frame("b", 11, Some(12))
+            },
+            RawFrame {
+                project: "other".to_string(),
+                ..frame("c", 13, None)
+            },
+        ];
+
+        assert!(diagnose(&frames, true, "%H:%M").is_empty());
+    }
+
+    #[test]
+    fn concurrent_frames_of_the_same_project_conflict() {
+        let frames = vec![
+            frame("a", 10, None),
+            frame("b", 11, Some(12)),
+            frame("c", 13, None),
+        ];
+
+        let problems = diagnose(&frames, true, "%H:%M");
+        assert_eq!(2, problems.len());
+        assert!(problems[0].description.contains("both running"));
+        assert_eq!(
+            Fix::Stop {
+                id: "a000000".to_string(),
+                at: at(11)
+            },
+            problems[1].fixes[0]
+        );
+    }
+}
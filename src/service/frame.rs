@@ -1,8 +1,10 @@
 use crate::database::{establish_connection, MyJsonType};
+use crate::error::{Error, Result};
 use crate::models::{Frame, NewFrame};
 use crate::schema::frames;
-use chrono::NaiveDateTime;
+use chrono::{Duration, Local, NaiveDateTime};
 
+use crate::diesel::BoolExpressionMethods;
 use crate::diesel::ExpressionMethods;
 use crate::diesel::QueryDsl;
 use crate::diesel::RunQueryDsl;
@@ -10,37 +12,41 @@ use crate::diesel::TextExpressionMethods;
 
 use uuid::Uuid;
 
-pub fn frame_start_collides(start_b: &NaiveDateTime) -> bool {
+pub fn frame_start_collides(start_b: &NaiveDateTime) -> Result<bool> {
     use crate::schema::frames::dsl::*;
 
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     let results = frames
         .filter(deleted.eq(false))
         .filter(end.gt(start_b))
         .order_by(start.desc())
-        .load::<Frame>(&mut conn)
-        .expect("Error loading frames");
+        .load::<Frame>(&mut conn)?;
 
-    !results.is_empty()
+    Ok(!results.is_empty())
 }
 
-pub fn frame_collides(start_b: &NaiveDateTime, end_b: &NaiveDateTime) -> bool {
+pub fn frame_collides(start_b: &NaiveDateTime, end_b: &NaiveDateTime) -> Result<bool> {
     use crate::schema::frames::dsl::*;
 
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
     let results = frames
         .filter(deleted.eq(false))
         .filter(start.lt(end_b))
         .filter(end.gt(start_b))
         .order_by(start.desc())
-        .load::<Frame>(&mut conn)
-        .expect("Error loading frames");
+        .load::<Frame>(&mut conn)?;
 
-    !results.is_empty()
+    Ok(!results.is_empty())
 }
 
-pub fn create_frame(start: &NaiveDateTime, end: &NaiveDateTime, project: &str, tags: Vec<String>) {
+pub fn create_frame(
+    start: &NaiveDateTime,
+    end: &NaiveDateTime,
+    project: &str,
+    tags: Vec<String>,
+    note: Option<&str>,
+) -> Result<()> {
     use serde_json::json;
 
     let uuid: Uuid = Uuid::new_v4();
@@ -48,25 +54,32 @@ pub fn create_frame(start: &NaiveDateTime, end: &NaiveDateTime, project: &str, t
 
     let tags: MyJsonType = MyJsonType(json!(tags));
 
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     let new_frame = NewFrame {
         id: &uuid.to_string(),
         start,
         end: end_value,
-        last_update: &NaiveDateTime::default(),
+        last_update: &Local::now().naive_local(),
         project,
         tags: &tags,
         deleted: &false,
+        note,
     };
 
     diesel::insert_into(frames::table)
         .values(&new_frame)
-        .execute(&mut conn)
-        .expect("Error saving new frame");
+        .execute(&mut conn)?;
+
+    Ok(())
 }
 
-pub fn start_frame(start: &NaiveDateTime, project: &str, tags: Vec<String>) {
+pub fn start_frame(
+    start: &NaiveDateTime,
+    project: &str,
+    tags: Vec<String>,
+    note: Option<&str>,
+) -> Result<String> {
     use serde_json::json;
 
     let uuid: Uuid = Uuid::new_v4();
@@ -74,94 +87,307 @@ pub fn start_frame(start: &NaiveDateTime, project: &str, tags: Vec<String>) {
     let end: Option<&NaiveDateTime> = Option::None;
 
     let tags: MyJsonType = MyJsonType(json!(tags));
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     let new_frame = NewFrame {
         id: &uuid.to_string(),
         start,
         end,
-        last_update: &NaiveDateTime::default(),
+        last_update: &Local::now().naive_local(),
         project,
         tags: &tags,
         deleted: &false,
+        note,
     };
 
     diesel::insert_into(frames::table)
         .values(&new_frame)
-        .execute(&mut conn)
-        .expect("Error saving new frame");
+        .execute(&mut conn)?;
+
+    Ok(uuid.to_string())
+}
+
+pub fn stop_frame(frame: &Frame, at: &NaiveDateTime) -> Result<()> {
+    let mut conn = establish_connection()?;
+
+    diesel::update(frame)
+        .set(frames::end.eq(at))
+        .execute(&mut conn)?;
+
+    Ok(())
 }
 
-pub fn last_started_frame() -> Option<Frame> {
+pub fn last_started_frame() -> Result<Option<Frame>> {
     use crate::schema::frames::dsl::*;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     frames
         .filter(deleted.eq(false))
         .filter(end.is_null())
         .order_by(start.desc())
         .load::<Frame>(&mut conn)
-        .expect("Error loading frames")
-        .pop()
+        .map(|mut results| results.pop())
+        .map_err(Error::from)
+}
+
+pub fn running_frames() -> Result<Vec<Frame>> {
+    use crate::schema::frames::dsl::*;
+    let mut conn = establish_connection()?;
+
+    frames
+        .filter(deleted.eq(false))
+        .filter(end.is_null())
+        .order_by(start.asc())
+        .load::<Frame>(&mut conn)
+        .map_err(Error::from)
 }
 
-pub fn last_finished_frame() -> Option<Frame> {
+pub fn last_finished_frame() -> Result<Option<Frame>> {
     use crate::schema::frames::dsl::*;
     use std::collections::VecDeque;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     let results = frames
         .filter(deleted.eq(false))
         .filter(end.is_not_null())
         .order_by(end.desc())
-        .load::<Frame>(&mut conn)
-        .expect("Error loading frames");
+        .load::<Frame>(&mut conn)?;
 
-    VecDeque::from_iter(results).pop_front()
+    Ok(VecDeque::from_iter(results).pop_front())
 }
 
-pub fn last_created_frame() -> Option<Frame> {
+pub fn last_created_frame() -> Result<Option<Frame>> {
     use crate::schema::frames::dsl::*;
     use std::collections::VecDeque;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
     let results = frames
         .filter(deleted.eq(false))
         .order_by(last_update.desc())
-        .load::<Frame>(&mut conn)
-        .expect("Error loading frames");
+        .load::<Frame>(&mut conn)?;
 
-    VecDeque::from_iter(results).pop_front()
+    Ok(VecDeque::from_iter(results).pop_front())
 }
 
-pub fn find_frame(id_string: &String) -> Result<Frame, diesel::result::Error> {
+pub fn find_frame(id_string: &String) -> Result<Frame> {
     use crate::schema::frames::dsl::*;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
-    frames.find(id_string).first(&mut conn)
+    frames
+        .find(id_string)
+        .first(&mut conn)
+        .map_err(|error| not_found(error, id_string))
 }
 
-pub fn find_frame_by_short(id_string: &String) -> Result<Frame, diesel::result::Error> {
+pub fn find_frame_by_short(id_string: &String) -> Result<Frame> {
     use crate::schema::frames::dsl::*;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
-    let results = frames
+    frames
         .filter(deleted.eq(false))
         .filter(id.like(id_string.to_owned() + "%"))
-        .first::<Frame>(&mut conn);
+        .first::<Frame>(&mut conn)
+        .map_err(|error| not_found(error, id_string))
+}
 
-    return results;
+fn not_found(error: diesel::result::Error, id_string: &str) -> Error {
+    match error {
+        diesel::result::Error::NotFound => Error::FrameNotFound(id_string.to_string()),
+        error => Error::Query(error),
+    }
 }
 
-pub fn find_all() -> Vec<Frame> {
+pub fn find_all() -> Result<Vec<Frame>> {
     use crate::schema::frames::dsl::*;
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
 
-    let results = frames
+    frames
         .filter(deleted.eq(false))
         .order_by(last_update.desc())
         .load::<Frame>(&mut conn)
-        .expect("Error loading frames");
+        .map_err(Error::from)
+}
+
+pub fn find_between(
+    from: Option<&NaiveDateTime>,
+    to: Option<&NaiveDateTime>,
+    project_names: &[String],
+) -> Result<Vec<Frame>> {
+    use crate::schema::frames::dsl::*;
+    let mut conn = establish_connection()?;
+
+    let mut query = frames
+        .filter(deleted.eq(false))
+        .order_by(start.asc())
+        .into_boxed();
+
+    if let Some(from) = from {
+        query = query.filter(start.ge(from));
+    }
+    if let Some(to) = to {
+        query = query.filter(start.le(to));
+    }
+    if !project_names.is_empty() {
+        query = query.filter(project.eq_any(project_names));
+    }
+
+    query.load::<Frame>(&mut conn).map_err(Error::from)
+}
+
+pub fn overlapping_frames(
+    start_b: &NaiveDateTime,
+    end_b: Option<&NaiveDateTime>,
+    excluded_ids: &[String],
+) -> Result<Vec<Frame>> {
+    use crate::schema::frames::dsl::*;
+    let mut conn = establish_connection()?;
+
+    let mut query = frames
+        .filter(deleted.eq(false))
+        .filter(id.ne_all(excluded_ids))
+        .filter(end.gt(start_b).or(end.is_null()))
+        .order_by(start.asc())
+        .into_boxed();
+
+    if let Some(end_b) = end_b {
+        query = query.filter(start.lt(end_b));
+    }
+
+    query.load::<Frame>(&mut conn).map_err(Error::from)
+}
+
+pub fn ranges_overlap(
+    start_a: &NaiveDateTime,
+    end_a: Option<&NaiveDateTime>,
+    start_b: &NaiveDateTime,
+    end_b: Option<&NaiveDateTime>,
+) -> bool {
+    end_b.is_none_or(|end_b| start_a < end_b) && end_a.is_none_or(|end_a| start_b < end_a)
+}
+
+pub fn split_frame(
+    frame: &Frame,
+    at: &NaiveDateTime,
+    project: &str,
+    tags: Vec<String>,
+) -> Result<String> {
+    use diesel::Connection;
+    use serde_json::json;
+
+    let uuid: Uuid = Uuid::new_v4();
+    let tags: MyJsonType = MyJsonType(json!(tags));
+
+    let new_frame = NewFrame {
+        id: &uuid.to_string(),
+        start: at,
+        end: frame.end.as_ref(),
+        last_update: &Local::now().naive_local(),
+        project,
+        tags: &tags,
+        deleted: &false,
+        note: frame.note.as_deref(),
+    };
+
+    let mut conn = establish_connection()?;
+    conn.transaction(|conn| {
+        diesel::update(frame)
+            .set(frames::end.eq(at))
+            .execute(conn)?;
+        diesel::insert_into(frames::table)
+            .values(&new_frame)
+            .execute(conn)?;
+
+        Ok(uuid.to_string())
+    })
+}
+
+pub fn join_frames(first: &Frame, second: &Frame) -> Result<()> {
+    use diesel::Connection;
+    use serde_json::json;
+
+    let mut tags = first.tags.values();
+    for tag in second.tags.values() {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    let note = match (&first.note, &second.note) {
+        (Some(first), Some(second)) if first != second => Some(format!("{}; {}", first, second)),
+        (first, second) => first.to_owned().or_else(|| second.to_owned()),
+    };
+
+    let mut conn = establish_connection()?;
+    conn.transaction(|conn| {
+        diesel::update(first)
+            .set((
+                frames::end.eq(second.end),
+                frames::tags.eq(MyJsonType(json!(tags))),
+                frames::note.eq(note),
+            ))
+            .execute(conn)?;
+        diesel::update(second)
+            .set(frames::deleted.eq(true))
+            .execute(conn)?;
+
+        Ok(())
+    })
+}
+
+pub fn shift_frames(shifted: &[Frame], by: &Duration) -> Result<()> {
+    use diesel::Connection;
+
+    let mut conn = establish_connection()?;
+    conn.transaction(|conn| {
+        for frame in shifted {
+            diesel::update(frame)
+                .set((
+                    frames::start.eq(frame.start + *by),
+                    frames::end.eq(frame.end.map(|end| end + *by)),
+                ))
+                .execute(conn)?;
+        }
+
+        Ok(())
+    })
+}
 
-    return results;
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use super::ranges_overlap;
+
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn adjacent_ranges_do_not_overlap() {
+        assert!(!ranges_overlap(
+            &at(10),
+            Some(&at(11)),
+            &at(11),
+            Some(&at(12))
+        ));
+    }
+
+    #[test]
+    fn intersecting_ranges_overlap() {
+        assert!(ranges_overlap(
+            &at(10),
+            Some(&at(12)),
+            &at(11),
+            Some(&at(13))
+        ));
+    }
+
+    #[test]
+    fn open_range_overlaps_later_ranges() {
+        assert!(ranges_overlap(&at(10), None, &at(11), Some(&at(12))));
+        assert!(!ranges_overlap(&at(10), None, &at(8), Some(&at(9))));
+    }
 }
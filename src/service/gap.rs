@@ -0,0 +1,133 @@
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime, Weekday};
+
+use chrono::Datelike;
+
+use crate::error::Result;
+use crate::service::frame::overlapping_frames;
+
+pub fn working_intervals(
+    from: &NaiveDateTime,
+    to: &NaiveDateTime,
+    day_start: &NaiveTime,
+    day_end: &NaiveTime,
+    days: &[Weekday],
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut intervals = vec![];
+    let mut date = from.date();
+
+    while date <= to.date() {
+        if days.contains(&date.weekday()) {
+            let start = date.and_time(*day_start).max(*from);
+            let end = date.and_time(*day_end).min(*to);
+            if start < end {
+                intervals.push((start, end));
+            }
+        }
+        date = date.succ_opt().unwrap();
+    }
+
+    intervals
+}
+
+pub fn subtract(
+    interval: &(NaiveDateTime, NaiveDateTime),
+    tracked: &[(NaiveDateTime, NaiveDateTime)],
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut tracked = tracked.to_vec();
+    tracked.sort();
+
+    let mut gaps = vec![];
+    let mut position = interval.0;
+    for (start, end) in tracked {
+        if start > position {
+            gaps.push((position, start.min(interval.1)));
+        }
+        position = position.max(end);
+        if position >= interval.1 {
+            break;
+        }
+    }
+    if position < interval.1 {
+        gaps.push((position, interval.1));
+    }
+
+    gaps.into_iter()
+        .filter(|(start, end)| start < end)
+        .collect()
+}
+
+pub fn find_gaps(
+    from: &NaiveDateTime,
+    to: &NaiveDateTime,
+    day_start: &NaiveTime,
+    day_end: &NaiveTime,
+    days: &[Weekday],
+    min: &Duration,
+) -> Result<Vec<(NaiveDateTime, NaiveDateTime)>> {
+    let now = Local::now().naive_local();
+    let to = to.min(&now);
+
+    let mut gaps = vec![];
+    for interval in working_intervals(from, to, day_start, day_end, days) {
+        let tracked: Vec<(NaiveDateTime, NaiveDateTime)> =
+            overlapping_frames(&interval.0, Some(&interval.1), &[])?
+                .iter()
+                .map(|frame| (frame.start, frame.end.unwrap_or(now)))
+                .collect();
+
+        gaps.extend(
+            subtract(&interval, &tracked)
+                .into_iter()
+                .filter(|(start, end)| *end - *start >= *min),
+        );
+    }
+
+    Ok(gaps)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+
+    use super::{subtract, working_intervals};
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2000, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn working_intervals_skip_days_off() {
+        let intervals = working_intervals(
+            &at(1, 0, 0),
+            &at(4, 0, 0),
+            &NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            &NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            &[Weekday::Mon],
+        );
+
+        assert_eq!(vec![(at(3, 9, 0), at(3, 17, 0))], intervals);
+    }
+
+    #[test]
+    fn subtract_tracked_time() {
+        let gaps = subtract(
+            &(at(3, 9, 0), at(3, 17, 0)),
+            &[(at(3, 12, 0), at(3, 13, 0)), (at(3, 8, 0), at(3, 10, 0))],
+        );
+
+        assert_eq!(
+            vec![(at(3, 10, 0), at(3, 12, 0)), (at(3, 13, 0), at(3, 17, 0))],
+            gaps
+        );
+    }
+
+    #[test]
+    fn subtract_fully_tracked_interval() {
+        let gaps = subtract(&(at(3, 9, 0), at(3, 17, 0)), &[(at(3, 8, 0), at(3, 18, 0))]);
+
+        assert!(gaps.is_empty());
+    }
+}
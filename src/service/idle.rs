@@ -0,0 +1,72 @@
+use chrono::{Duration, Local, NaiveDateTime};
+
+use crate::error::Result;
+use crate::models::Frame;
+use crate::service::frame::running_frames;
+
+/// Running frames which exceed the given maximum duration, together with the
+/// time at which they should have been stopped.
+pub fn idle_frames(max: &Duration) -> Result<Vec<(Frame, NaiveDateTime)>> {
+    let now = Local::now().naive_local();
+
+    Ok(running_frames()?
+        .into_iter()
+        .filter_map(|frame| suggested_end(&frame, max, &now).map(|end| (frame, end)))
+        .collect())
+}
+
+pub fn suggested_end(frame: &Frame, max: &Duration, now: &NaiveDateTime) -> Option<NaiveDateTime> {
+    let end = frame.start + *max;
+
+    (frame.end.is_none() && end < *now).then_some(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, NaiveDateTime};
+    use serde_json::json;
+
+    use super::suggested_end;
+    use crate::database::MyJsonType;
+    use crate::models::Frame;
+
+    fn at(hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn frame(start: u32, end: Option<u32>) -> Frame {
+        Frame {
+            id: "a000000".to_string(),
+            start: at(start),
+            end: end.map(at),
+            last_update: at(start),
+            project: "test".to_string(),
+            tags: MyJsonType(json!([])),
+            deleted: false,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn running_frame_exceeding_maximum_is_idle() {
+        assert_eq!(
+            Some(at(12)),
+            suggested_end(&frame(8, None), &Duration::hours(4), &at(20))
+        );
+    }
+
+    #[test]
+    fn short_or_finished_frames_are_not_idle() {
+        assert_eq!(
+            None,
+            suggested_end(&frame(8, None), &Duration::hours(4), &at(10))
+        );
+        assert_eq!(
+            None,
+            suggested_end(&frame(8, Some(20)), &Duration::hours(4), &at(21))
+        );
+    }
+}
@@ -1,3 +1,7 @@
+pub mod doctor;
+pub mod directory;
 pub mod frame;
+pub mod gap;
+pub mod idle;
 pub mod project;
 pub mod tags;
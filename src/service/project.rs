@@ -1,8 +1,9 @@
 use crate::database::establish_connection;
+use crate::error::Result;
 
 use diesel::prelude::*;
 
-pub fn has_project(project: String) -> bool {
+pub fn has_project(project: String) -> Result<bool> {
     use diesel::sql_types::VarChar;
 
     #[derive(QueryableByName)]
@@ -11,21 +12,20 @@ pub fn has_project(project: String) -> bool {
         name: String,
     }
 
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
     let results = diesel::sql_query(r#"SELECT DISTINCT project AS name FROM frames"#)
-        .load::<Project>(&mut conn)
-        .expect("Query failed");
+        .load::<Project>(&mut conn)?;
 
     for result in results {
         if result.name == project {
-            return true;
+            return Ok(true);
         }
     }
 
-    false
+    Ok(false)
 }
 
-pub fn find_all() -> Vec<String> {
+pub fn find_all() -> Result<Vec<String>> {
     use diesel::sql_types::VarChar;
 
     #[derive(QueryableByName)]
@@ -34,15 +34,63 @@ pub fn find_all() -> Vec<String> {
         name: String,
     }
 
-    let mut conn = establish_connection();
+    let mut conn = establish_connection()?;
     let results = diesel::sql_query(r#"SELECT DISTINCT project AS name FROM frames"#)
-        .load::<Project>(&mut conn)
-        .expect("Query failed");
+        .load::<Project>(&mut conn)?;
 
     let mut project_strings : Vec<String> = vec![];
     for result in results {
         project_strings.push(result.name)
     }
 
-    project_strings
+    Ok(project_strings)
+}
+
+pub const SEPARATOR: char = '/';
+
+pub fn is_descendant_of(project: &str, parent: &str) -> bool {
+    project == parent
+        || project
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with(SEPARATOR))
+}
+
+pub fn with_descendants(parents: &[String]) -> Result<Vec<String>> {
+    let mut projects: Vec<String> = parents.to_vec();
+
+    for project in find_all()? {
+        if projects.contains(&project) {
+            continue;
+        }
+        if parents
+            .iter()
+            .any(|parent| is_descendant_of(&project, parent))
+        {
+            projects.push(project);
+        }
+    }
+
+    Ok(projects)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_descendant_of;
+
+    #[test]
+    fn project_is_descendant_of_itself() {
+        assert!(is_descendant_of("client", "client"));
+    }
+
+    #[test]
+    fn nested_project_is_descendant() {
+        assert!(is_descendant_of("client/project", "client"));
+        assert!(is_descendant_of("client/project/task", "client/project"));
+    }
+
+    #[test]
+    fn project_with_same_prefix_is_not_descendant() {
+        assert!(!is_descendant_of("clients", "client"));
+        assert!(!is_descendant_of("client", "client/project"));
+    }
 }
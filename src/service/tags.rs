@@ -1,8 +1,13 @@
 use crate::database::establish_connection;
+use crate::error::{Error, Result};
 
 use diesel::prelude::*;
 
-pub fn has_tag(tag: String) -> bool {
+pub fn has_tag(tag: String) -> Result<bool> {
+    Ok(find_all()?.contains(&tag))
+}
+
+pub fn find_all() -> Result<Vec<String>> {
     use diesel::sql_types::VarChar;
 
     #[derive(QueryableByName)]
@@ -11,20 +16,25 @@ pub fn has_tag(tag: String) -> bool {
         name: String,
     }
 
-    let mut conn = establish_connection();
-    let results = diesel::sql_query(r#"SELECT DISTINCT tags AS name FROM frames"#)
-        .load::<Tag>(&mut conn)
-        .expect("Query failed");
+    let mut conn = establish_connection()?;
+    let results =
+        diesel::sql_query(r#"SELECT DISTINCT tags AS name FROM frames WHERE deleted = 0"#)
+            .load::<Tag>(&mut conn)?;
 
+    let mut tag_strings: Vec<String> = vec![];
     for result in results {
-        let tags_json: Vec<String> =
-            serde_json::from_str(&result.name).expect("Tags are not valid json");
-        for tag_json in tags_json {
-            if tag_json == tag {
-                return true;
+        let tags_json: Option<Vec<String>> =
+            serde_json::from_str(&result.name).map_err(|source| Error::InvalidTags {
+                tags: result.name.to_string(),
+                source,
+            })?;
+        for tag in tags_json.unwrap_or_default() {
+            if !tag_strings.contains(&tag) {
+                tag_strings.push(tag);
             }
         }
     }
+    tag_strings.sort();
 
-    false
+    Ok(tag_strings)
 }
@@ -12,6 +12,12 @@ pub struct TestDb {
     pub tmp_dir: tempfile::TempDir,
 }
 
+impl Default for TestDb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TestDb {
     pub fn new() -> TestDb {
         let tmp_dir = tempfile::Builder::new()
@@ -34,6 +40,18 @@ impl TestDb {
         }
     }
 
+    /// Writes the given toml as aze configuration and returns the directory
+    /// which has to be passed as `XDG_CONFIG_HOME`.
+    pub fn config(&self, content: &str) -> std::path::PathBuf {
+        let config_home = self.tmp_dir.path().join("config");
+        let config_dir = config_home.join("aze");
+
+        std::fs::create_dir_all(&config_dir).expect("not possible to create config dir");
+        std::fs::write(config_dir.join("aze.toml"), content).expect("not possible to write config");
+
+        config_home
+    }
+
     pub fn conn(&self) -> Option<PooledConnection<ConnectionManager<SqliteConnection>>> {
         let pool = self.pool.get().ok();
         let mut conn = pool.unwrap();
@@ -43,7 +43,7 @@ fn start_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn std::erro
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 12:00")
         .arg("--to")
@@ -56,7 +56,7 @@ fn start_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn std::erro
     cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 15:00")
         .arg("--to")
@@ -79,7 +79,7 @@ fn end_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn std::error:
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 12:00")
         .arg("--to")
@@ -92,7 +92,7 @@ fn end_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn std::error:
     cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 10:00")
         .arg("--to")
@@ -115,7 +115,7 @@ fn start_and_end_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn s
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 12:00")
         .arg("--to")
@@ -128,7 +128,7 @@ fn start_and_end_in_overlapping_frame_returns_in_error() -> Result<(), Box<dyn s
     cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 13:00")
         .arg("--to")
@@ -151,7 +151,7 @@ fn adding_tags_to_frames() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 13:00")
         .arg("--to")
@@ -165,15 +165,15 @@ fn adding_tags_to_frames() -> Result<(), Box<dyn std::error::Error>> {
 
     let result = get_frames(&test_db);
     assert_eq!(1, result.len());
-    assert_eq!(1, result.get(0).expect("err").tags.values().len());
+    assert_eq!(1, result.first().expect("err").tags.values().len());
     assert_eq!(
         "tag1",
         result
-            .get(0)
+            .first()
             .expect("err")
             .tags
             .values()
-            .get(0)
+            .first()
             .expect("err")
     );
     Ok(())
@@ -186,7 +186,7 @@ fn frames_are_saved_to_database() -> Result<(), Box<dyn std::error::Error>> {
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("add")
-        .env("DATABASE_URL", &database)
+        .env("DATABASE_URL", database)
         .arg("--from")
         .arg("2000-01-01 13:00")
         .arg("--to")
@@ -201,7 +201,7 @@ fn frames_are_saved_to_database() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(
         "2000-01-01 13:00",
         result
-            .get(0)
+            .first()
             .unwrap()
             .start
             .format("%Y-%m-%d %H:%M")
@@ -211,7 +211,7 @@ fn frames_are_saved_to_database() -> Result<(), Box<dyn std::error::Error>> {
     assert_eq!(
         "2000-01-01 14:00",
         result
-            .get(0)
+            .first()
             .unwrap()
             .end
             .expect("err")
@@ -219,6 +219,6 @@ fn frames_are_saved_to_database() -> Result<(), Box<dyn std::error::Error>> {
             .to_string()
     );
 
-    assert_eq!("test", result.get(0).unwrap().project);
+    assert_eq!("test", result.first().unwrap().project);
     Ok(())
 }
@@ -0,0 +1,134 @@
+use assert_cmd::prelude::*;
+use chrono::{Local, NaiveDateTime};
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::add_frame;
+
+fn add_frames(test_db: &TestDb) -> Result<(), Box<dyn std::error::Error>> {
+    let start = Local::now().naive_local().timestamp() - 7200;
+
+    add_frame(
+        test_db,
+        "apollo11",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(start + 1800, 0).unwrap()),
+        Some(vec!["moon".to_string(), "rocket".to_string()]),
+    )?;
+    add_frame(
+        test_db,
+        "hubble",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(start + 5400, 0).unwrap()),
+        None,
+    )
+}
+
+#[test]
+fn completions_include_value_hook() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    for (shell, function) in [
+        ("bash", "_aze()"),
+        ("zsh", "_aze()"),
+        ("fish", "complete -c aze"),
+    ] {
+        let mut cmd = Command::cargo_bin("aze")?;
+        cmd.env("DATABASE_URL", database)
+            .arg("completions")
+            .arg(shell);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains(function))
+            .stdout(predicate::str::contains("aze __complete --"))
+            .stdout(predicate::str::contains("__complete)").not());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn complete_projects_for_start() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    add_frames(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .args(["__complete", "--", "aze", "start", "ap"]);
+
+    cmd.assert().success().stdout("apollo11\n");
+
+    Ok(())
+}
+
+#[test]
+fn complete_projects_for_log_option() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    add_frames(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .args(["__complete", "--", "aze", "log", "-d", "-p", ""]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("apollo11"))
+        .stdout(predicate::str::contains("hubble"));
+
+    Ok(())
+}
+
+#[test]
+fn complete_tags_after_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    add_frames(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .args(["__complete", "--", "aze", "add", "apollo11", "+r"]);
+
+    cmd.assert().success().stdout("+rocket\n");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).args([
+        "__complete",
+        "--",
+        "aze",
+        "start",
+        "apollo11",
+        "--note",
+        "launch",
+        "",
+    ]);
+
+    cmd.assert().success().stdout("");
+
+    Ok(())
+}
+
+#[test]
+fn complete_frame_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    add_frames(&test_db)?;
+
+    for command in ["edit", "remove"] {
+        let mut cmd = Command::cargo_bin("aze")?;
+        cmd.env("DATABASE_URL", database)
+            .args(["__complete", "--", "aze", command, ""]);
+
+        let mut assert = cmd.assert().success();
+        for frame in get_frames(&test_db) {
+            assert = assert.stdout(predicate::str::contains(&frame.id[..7]));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+#[test]
+fn config_path_in_config_home() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("path");
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        config_home.join("aze").join("aze.toml").to_str().unwrap(),
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn config_set_and_get() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("set")
+        .arg("working_days")
+        .arg("Mon,Tue");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Set working_days to Mon, Tue"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("get")
+        .arg("working_days");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("Mon, Tue\n"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("list");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "working_days = [\"Mon\", \"Tue\"]",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn config_set_rejects_invalid_values() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("set")
+        .arg("datetime_format")
+        .arg("%H:%M");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "has to contain a full date and time",
+    ));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("set")
+        .arg("stop_on_start")
+        .arg("maybe");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("expected true or false"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("get")
+        .arg("unknown");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown setting 'unknown'"));
+
+    Ok(())
+}
+
+#[test]
+fn config_edit_in_editor() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env(
+            "VISUAL",
+            "sed -i s/stop_on_start\\s=\\sfalse/stop_on_start=true/",
+        )
+        .arg("config")
+        .arg("edit");
+    cmd.assert().success();
+
+    let content = std::fs::read_to_string(config_home.join("aze").join("aze.toml"))?;
+    assert!(content.contains("stop_on_start = true"));
+
+    Ok(())
+}
+
+#[test]
+fn config_set_rejects_invalid_week_start() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("set")
+        .arg("week_start")
+        .arg("Someday");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid week_start 'Someday'"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_config_is_reported() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("log_range = [");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("status");
+    cmd.assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("Invalid"))
+        .stderr(predicate::str::contains("aze.toml"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("path");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("aze.toml"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_config_value_is_reported() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("time_format = \"%Q\"");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("log");
+    cmd.assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("Invalid time_format '%Q'"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("set")
+        .arg("time_format")
+        .arg("%H:%M");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("log");
+    cmd.assert().success();
+
+    Ok(())
+}
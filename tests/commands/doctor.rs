@@ -0,0 +1,341 @@
+use assert_cmd::prelude::*;
+
+use chrono::NaiveDate;
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::add_frame;
+
+#[test]
+fn report_overlapping_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("overlap"))
+        .stdout(predicate::str::contains("Run with --fix"));
+
+    let result = get_frames(&test_db);
+    assert!(result.iter().any(|frame| frame.end
+        == Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn fix_overlapping_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor").arg("--fix");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Fixed"));
+
+    let first = get_frames(&test_db)
+        .into_iter()
+        .find(|frame| frame.project == "test1")
+        .unwrap();
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(11, 0, 0)
+                .unwrap()
+        ),
+        first.end
+    );
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No problems found."));
+
+    Ok(())
+}
+
+#[test]
+fn fix_several_overlapping_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(14, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+    add_frame(
+        &test_db,
+        "test3",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(13, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(15, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor").arg("--fix");
+
+    let stdout = String::from_utf8(cmd.assert().success().get_output().stdout.clone())?;
+    assert_eq!(1, stdout.matches("End frame").count());
+
+    let first = get_frames(&test_db)
+        .into_iter()
+        .find(|frame| frame.project == "test1")
+        .unwrap();
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(11, 0, 0)
+                .unwrap()
+        ),
+        first.end
+    );
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No problems found."));
+
+    Ok(())
+}
+
+#[test]
+fn fix_frame_ending_before_start() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap(),
+        ),
+        Some(vec![]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor").arg("--fix");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("ends before it starts"));
+
+    let result = get_frames(&test_db);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        result[0].start
+    );
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+        ),
+        result[0].end
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fix_invalid_tags_and_update_time() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor").arg("--fix");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("invalid tags"))
+        .stdout(predicate::str::contains("no last update time"))
+        .stdout(predicate::str::contains("Fixed 2 of 2 problem(s)."));
+
+    let result = get_frames(&test_db);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        result[0].last_update
+    );
+    assert!(result[0].tags.values().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn report_several_running_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        None,
+        Some(vec![]),
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(11, 0, 0)
+            .unwrap(),
+        None,
+        Some(vec![]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("doctor");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("both running"));
+
+    Ok(())
+}
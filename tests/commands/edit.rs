@@ -1,11 +1,12 @@
 use assert_cmd::prelude::*;
 
-use chrono::Local;
+use chrono::NaiveDate;
 use predicates::prelude::*;
-use std::{process::{Command, Stdio}, fs::File};
+use std::os::unix::fs::PermissionsExt;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
 
-use crate::TestDb;
-use std::io::Read;
 use super::add_frame;
 
 #[test]
@@ -20,3 +21,374 @@ fn edit_no_project_started() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn edit_multiple_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(15, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/test1/test2/")
+        .arg("edit")
+        .arg("--from")
+        .arg("2000-01-01");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Updated 2 frame(s)."));
+
+    let result = get_frames(&test_db);
+    assert!(result.iter().all(|frame| frame.project == "test2"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_multiple_frames_rejects_overlap() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(15, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/T13:00/T14:30/;s/test1/test3/")
+        .arg("edit")
+        .arg("--project")
+        .arg("test1");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("overlaps with frame"));
+
+    let result = get_frames(&test_db);
+    assert!(result.iter().all(|frame| frame.project != "test3"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_multiple_frames_rejects_invalid_range() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/T13:00/T11:00/")
+        .arg("edit")
+        .arg("--project")
+        .arg("test1");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("end must be after start"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_multiple_frames_without_match() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.env("DATABASE_URL", "file::memory:?cache=shared")
+        .arg("edit")
+        .arg("--project")
+        .arg("unknown");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "No frames found for the given filters",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn edit_frame_rejects_invalid_range() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+    let id = &get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/T13:00/T11:00/")
+        .arg("edit")
+        .arg(&id[..7]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("end must be after start"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_frame_rejects_future_times() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/2000-01-01T13/2999-01-01T13/")
+        .arg("edit");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("end must not be in the future"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_frame_does_not_collide_with_itself() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/T13:00/T13:30/;s/test1/test2/")
+        .arg("edit");
+
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    assert_eq!("test2", result.first().unwrap().project);
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 30, 0)
+                .unwrap()
+        ),
+        result.first().unwrap().end
+    );
+
+    Ok(())
+}
+
+#[test]
+fn edit_frame_rejects_overlap() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(15, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+    let id = get_frames(&test_db)
+        .iter()
+        .find(|frame| frame.project == "test1")
+        .unwrap()
+        .id
+        .to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", "sed -i s/T13:00/T14:30/")
+        .arg("edit")
+        .arg(&id[..7]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("overlaps with frame"));
+
+    Ok(())
+}
+
+#[test]
+fn edit_frame_reopens_editor_with_error() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(13, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let editor = test_db.tmp_dir.path().join("editor.sh");
+    std::fs::write(
+        &editor,
+        "#!/bin/sh\nif grep -q 'end must be after start' \"$1\"; then sed -i s/T11:00/T12:30/ \"$1\"; else sed -i s/T13:00/T11:00/ \"$1\"; fi\n",
+    )?;
+    std::fs::set_permissions(&editor, std::fs::Permissions::from_mode(0o755))?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("VISUAL", &editor)
+        .arg("edit");
+
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 30, 0)
+                .unwrap()
+        ),
+        result.first().unwrap().end
+    );
+
+    Ok(())
+}
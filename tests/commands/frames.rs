@@ -1,11 +1,10 @@
 use assert_cmd::prelude::*;
 
-use chrono::{Local, NaiveDateTime, Date, NaiveDate};
+use chrono::NaiveDate;
 use predicates::prelude::*;
-use std::{process::{Command, Stdio}, fs::File};
+use std::process::Command;
 
 use crate::{TestDb, commands::get_frames};
-use std::io::Read;
 use super::add_frame;
 
 #[test]
@@ -28,9 +27,9 @@ fn frames_frame_saved() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11),
-        Option::from(&NaiveDate::from_ymd(2016, 7, 8).and_hms(10, 11, 12)),
+        "test",
+        &NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
         None
     )?;
 
@@ -38,15 +37,15 @@ fn frames_frame_saved() -> Result<(), Box<dyn std::error::Error>> {
     let result = get_frames(&test_db);
     assert_eq!(1, result.len());
 
-    let id = &result.get(0).expect("fail").id;
+    let id = &result.first().expect("fail").id;
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("frames");
+    cmd.env("DATABASE_URL", database).arg("frames");
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains(&id[..7].to_string()));
+        .stdout(predicate::str::contains(&id[..7]));
 
 
 
@@ -0,0 +1,219 @@
+use assert_cmd::prelude::*;
+
+use chrono::NaiveDate;
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::add_frame;
+
+#[test]
+fn gaps_within_working_hours() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDate::from_ymd_opt(2000, 1, 3)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 3)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("gaps")
+        .arg("--from")
+        .arg("2000-01-03")
+        .arg("--to")
+        .arg("2000-01-03 23:59");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Mon 3 1 2000"))
+        .stdout(predicate::str::contains("09:00 to 10:00\t1h 00m 00s"))
+        .stdout(predicate::str::contains("12:00 to 17:00\t5h 00m 00s"));
+
+    Ok(())
+}
+
+#[test]
+fn gaps_skip_weekends() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", "file::memory:?cache=shared")
+        .arg("gaps")
+        .arg("--from")
+        .arg("2000-01-01")
+        .arg("--to")
+        .arg("2000-01-02 23:59");
+
+    cmd.assert().success().stdout(predicate::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn gaps_ignore_short_gaps() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDate::from_ymd_opt(2000, 1, 3)
+            .unwrap()
+            .and_hms_opt(9, 10, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 3)
+                .unwrap()
+                .and_hms_opt(17, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("gaps")
+        .arg("--from")
+        .arg("2000-01-03")
+        .arg("--to")
+        .arg("2000-01-03 23:59")
+        .arg("--min")
+        .arg("15m");
+
+    cmd.assert().success().stdout(predicate::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn fill_creates_frames_in_gaps() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDate::from_ymd_opt(2000, 1, 3)
+            .unwrap()
+            .and_hms_opt(10, 0, 0)
+            .unwrap(),
+        Some(
+            &NaiveDate::from_ymd_opt(2000, 1, 3)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        ),
+        None,
+    )?;
+
+    let mut cmd = assert_cmd::Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("fill")
+        .arg("apollo11")
+        .arg("+brakes")
+        .arg("--from")
+        .arg("2000-01-03")
+        .arg("--to")
+        .arg("2000-01-03 23:59");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Filled 2 gap(s)."));
+
+    let mut result = get_frames(&test_db);
+    result.sort_by_key(|frame| frame.start);
+    assert_eq!(3, result.len());
+    assert_eq!("apollo11", result[0].project);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2000, 1, 3)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap(),
+        result[0].start
+    );
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 3)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap()
+        ),
+        result[0].end
+    );
+    assert_eq!(vec!["brakes".to_string()], result[0].tags.values());
+    assert_eq!("apollo11", result[2].project);
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2000, 1, 3)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap(),
+        result[2].start
+    );
+    assert_eq!(
+        Some(
+            NaiveDate::from_ymd_opt(2000, 1, 3)
+                .unwrap()
+                .and_hms_opt(17, 0, 0)
+                .unwrap()
+        ),
+        result[2].end
+    );
+    assert_eq!(vec!["brakes".to_string()], result[2].tags.values());
+
+    Ok(())
+}
+
+#[test]
+fn fill_without_input_skips_gaps() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("fill")
+        .arg("--from")
+        .arg("2000-01-03")
+        .arg("--to")
+        .arg("2000-01-03 23:59");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Filled 0 gap(s)."));
+
+    assert!(get_frames(&test_db).is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn fill_with_invalid_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = assert_cmd::Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("fill")
+        .arg("apollo11")
+        .arg("brakes")
+        .arg("--from")
+        .arg("2000-01-03")
+        .arg("--to")
+        .arg("2000-01-03 23:59");
+
+    cmd.assert().failure();
+    assert!(get_frames(&test_db).is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+#[test]
+fn help_lists_topics() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path).arg("help");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SUBCOMMANDS:"))
+        .stdout(predicate::str::contains("TOPICS:"))
+        .stdout(predicate::str::contains("dates"))
+        .stdout(predicate::str::contains("filters"));
+
+    Ok(())
+}
+
+#[test]
+fn help_shows_topic() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("help")
+        .arg("dates");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("datetime_format"));
+
+    Ok(())
+}
+
+#[test]
+fn help_shows_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("help")
+        .arg("start");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("aze start [OPTIONS]"))
+        .stdout(predicate::str::contains("--confirm-new-project"));
+
+    Ok(())
+}
+
+#[test]
+fn help_for_unknown_topic() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("help")
+        .arg("unknown");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "No subcommand or help topic 'unknown'",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn config_topic_describes_all_keys() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("list");
+    let list = String::from_utf8(cmd.output()?.stdout)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("help")
+        .arg("config");
+    let mut assert = cmd.assert().success();
+
+    for line in list.lines() {
+        let key = line.split(" = ").next().unwrap();
+        assert = assert.stdout(predicate::str::contains(key));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::{add_frame, datetime};
+
+fn frame_id(test_db: &TestDb, hour: u32) -> String {
+    get_frames(test_db)
+        .iter()
+        .find(|frame| frame.start == datetime(hour, 0))
+        .unwrap()
+        .id[..7]
+        .to_string()
+}
+
+#[test]
+fn join_adjacent_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        Some(vec!["tag1".to_string()]),
+    )?;
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(13, 0),
+        Some(&datetime(14, 0)),
+        Some(vec!["tag2".to_string()]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("join")
+        .arg(frame_id(&test_db, 13))
+        .arg(frame_id(&test_db, 12));
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Joined frames"));
+
+    let result: Vec<_> = get_frames(&test_db)
+        .into_iter()
+        .filter(|frame| !frame.deleted)
+        .collect();
+    assert_eq!(1, result.len());
+    assert_eq!(datetime(12, 0), result[0].start);
+    assert_eq!(Some(datetime(14, 0)), result[0].end);
+    assert_eq!(
+        vec!["tag1".to_string(), "tag2".to_string()],
+        result[0].tags.values()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn join_frames_of_different_projects() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &datetime(13, 0),
+        Some(&datetime(14, 0)),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("join")
+        .arg(frame_id(&test_db, 12))
+        .arg(frame_id(&test_db, 13));
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("belong to different projects"));
+
+    Ok(())
+}
+
+#[test]
+fn join_frames_with_frame_in_between() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "other",
+        &datetime(14, 0),
+        Some(&datetime(14, 30)),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(15, 0),
+        Some(&datetime(16, 0)),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("join")
+        .arg(frame_id(&test_db, 12))
+        .arg(frame_id(&test_db, 15));
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("are not adjacent"));
+
+    Ok(())
+}
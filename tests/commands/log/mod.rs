@@ -19,7 +19,7 @@ fn nothing_if_no_entries() -> Result<(), Box<dyn std::error::Error>> {
     let database = dir.path().join("database.db");
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.arg("log").env("DATABASE_URL", &database);
+    cmd.arg("log").env("DATABASE_URL", database);
 
     cmd.assert().success().stdout(predicate::str::is_empty());
 
@@ -34,13 +34,13 @@ fn entry_from_this_day() -> Result<(), Box<dyn std::error::Error>> {
     let start = Local::now().naive_local().timestamp() - 3600;
     let end = start + 1800;
 
-    let dt_start = NaiveDateTime::from_timestamp(start, 0);
-    let dt_end = NaiveDateTime::from_timestamp(end, 0);
+    let dt_start = NaiveDateTime::from_timestamp_opt(start, 0).unwrap();
+    let dt_end = NaiveDateTime::from_timestamp_opt(end, 0).unwrap();
 
-    add_frame(&test_db, &"test", &dt_start, Some(&dt_end), None)?;
+    add_frame(&test_db, "test", &dt_start, Some(&dt_end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.arg("log").env("DATABASE_URL", &database);
+    cmd.arg("log").env("DATABASE_URL", database);
 
     cmd.assert()
         .success()
@@ -57,13 +57,13 @@ fn entry_from_last_two_weeks_default_not_shown() -> Result<(), Box<dyn std::erro
     let start = Local::now().naive_local().timestamp() - 3600 * 24 * 10;
     let end = start + 1800;
 
-    let dt_start = NaiveDateTime::from_timestamp(start, 0);
-    let dt_end = NaiveDateTime::from_timestamp(end, 0);
+    let dt_start = NaiveDateTime::from_timestamp_opt(start, 0).unwrap();
+    let dt_end = NaiveDateTime::from_timestamp_opt(end, 0).unwrap();
 
-    add_frame(&test_db, &"test", &dt_start, Some(&dt_end), None)?;
+    add_frame(&test_db, "test", &dt_start, Some(&dt_end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.arg("log").env("DATABASE_URL", &database);
+    cmd.arg("log").env("DATABASE_URL", database);
 
     cmd.assert().success().stdout(predicate::str::is_empty());
 
@@ -79,21 +79,21 @@ fn duration_correctly_calculated() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.arg("log").env("DATABASE_URL", &database);
+    cmd.arg("log").env("DATABASE_URL", database);
 
     cmd.assert()
         .success()
@@ -111,14 +111,14 @@ fn entries_does_not_cover_current_frame() -> Result<(), Box<dyn std::error::Erro
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
         None,
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database).arg("log");
+    cmd.env("DATABASE_URL", database).arg("log");
 
     cmd.assert().success().stdout(predicate::str::is_empty());
 
@@ -133,14 +133,14 @@ fn entries_cover_current_frame_if_requested() -> Result<(), Box<dyn std::error::
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
         None,
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database).arg("log").arg("-c");
+    cmd.env("DATABASE_URL", database).arg("log").arg("-c");
 
     cmd.assert()
         .success()
@@ -158,21 +158,21 @@ fn order_newest_top() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database).arg("log").arg("-c");
+    cmd.env("DATABASE_URL", database).arg("log").arg("-c");
 
     cmd.assert().success();
     let stdout = String::from_utf8(cmd.output().expect("err").stdout)
@@ -193,21 +193,21 @@ fn order_oldest_top() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-c")
         .arg("-r");
@@ -221,3 +221,204 @@ fn order_oldest_top() -> Result<(), Box<dyn std::error::Error>> {
     assert!(re.is_match(stdout.as_str()), "Output: {}", stdout);
     Ok(())
 }
+
+#[test]
+fn entries_filtered_by_note() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+
+    for (project, note, offset) in [("test1", "ticket ABC-12", 0), ("test2", "meeting", 3600)] {
+        let mut cmd = Command::cargo_bin("aze")?;
+        cmd.env("DATABASE_URL", database)
+            .arg("add")
+            .arg(project)
+            .arg("--from")
+            .arg(
+                NaiveDateTime::from_timestamp_opt(start + offset, 0)
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )
+            .arg("--to")
+            .arg(
+                NaiveDateTime::from_timestamp_opt(start + offset + 1800, 0)
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )
+            .arg("-n")
+            .arg(note);
+        cmd.assert().success();
+    }
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("log")
+        .arg("--grep")
+        .arg("abc");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test1"))
+        .stdout(predicate::str::contains("ticket ABC-12"))
+        .stdout(predicate::str::contains("test2").not());
+
+    Ok(())
+}
+
+#[test]
+fn entries_filtered_by_note_literally() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+
+    for (project, note, offset) in [("test1", "100%_done", 0), ("test2", "1000 done", 3600)] {
+        let mut cmd = Command::cargo_bin("aze")?;
+        cmd.env("DATABASE_URL", database)
+            .arg("add")
+            .arg(project)
+            .arg("--from")
+            .arg(
+                NaiveDateTime::from_timestamp_opt(start + offset, 0)
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )
+            .arg("--to")
+            .arg(
+                NaiveDateTime::from_timestamp_opt(start + offset + 1800, 0)
+                    .unwrap()
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string(),
+            )
+            .arg("-n")
+            .arg(note);
+        cmd.assert().success();
+    }
+
+    for text in ["100%", "_done", "%_"] {
+        let mut cmd = Command::cargo_bin("aze")?;
+        cmd.env("DATABASE_URL", database)
+            .arg("log")
+            .arg("--grep")
+            .arg(text);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("test1"))
+            .stdout(predicate::str::contains("test2").not());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn entries_use_configured_formats() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db
+        .config("date_format = \"%Y/%m/%d\"\ntime_format = \"%Hh%M\"\nduration_format = \"%H:%M\"");
+
+    let dt_start =
+        NaiveDateTime::from_timestamp_opt(Local::now().naive_local().timestamp() - 3600, 0)
+            .unwrap();
+    let dt_end = dt_start + chrono::Duration::minutes(30);
+
+    add_frame(&test_db, "test", &dt_start, Some(&dt_end), None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.arg("log")
+        .env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            dt_start.format("%Y/%m/%d (0:30)").to_string(),
+        ))
+        .stdout(predicate::str::contains(format!(
+            "{} to {}\t0:30",
+            dt_start.format("%Hh%M"),
+            dt_end.format("%Hh%M")
+        )));
+
+    Ok(())
+}
+
+#[test]
+fn entries_cover_current_frame_if_configured() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("log_current = true");
+
+    add_frame(
+        &test_db,
+        "test",
+        &(Local::now().naive_local() - chrono::Duration::minutes(30)),
+        None,
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.arg("log")
+        .env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test"));
+
+    Ok(())
+}
+
+#[test]
+fn entries_from_configured_range() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("log_range = \"30d\"");
+
+    let dt_start = Local::now().naive_local() - chrono::Duration::days(14);
+    add_frame(
+        &test_db,
+        "test",
+        &dt_start,
+        Some(&(dt_start + chrono::Duration::hours(1))),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.arg("log")
+        .env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test"));
+
+    Ok(())
+}
+
+#[test]
+fn pager_is_skipped_without_terminal() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let dt_start =
+        NaiveDateTime::from_timestamp_opt(Local::now().naive_local().timestamp() - 3600, 0)
+            .unwrap();
+    let dt_end = dt_start + chrono::Duration::minutes(30);
+    add_frame(&test_db, "test", &dt_start, Some(&dt_end), None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.arg("log")
+        .arg("--pager")
+        .env("PAGER", "does-not-exist")
+        .env("DATABASE_URL", database);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("0h 30m 00s"));
+
+    Ok(())
+}
@@ -16,21 +16,21 @@ fn get_only_correct_project_entries() -> Result<(), Box<dyn std::error::Error>>
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-p")
         .arg("test1");
@@ -52,21 +52,21 @@ fn get_only_correct_projects_entries() -> Result<(), Box<dyn std::error::Error>>
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-p")
         .arg("test1")
@@ -90,21 +90,21 @@ fn ignore_single_project() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--ignore-project")
         .arg("test2");
@@ -126,21 +126,21 @@ fn ignore_multiple_projects() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--ignore-project")
         .arg("test1")
@@ -160,7 +160,7 @@ fn ignore_and_select_projects() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-p")
         .arg("test1")
@@ -173,3 +173,75 @@ fn ignore_and_select_projects() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn get_sub_project_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "client/project",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "clients",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("log")
+        .arg("-p")
+        .arg("client");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("client/project"))
+        .stdout(predicate::str::contains("clients").not());
+
+    Ok(())
+}
+
+#[test]
+fn ignore_sub_project_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "client/project",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "other",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("log")
+        .arg("--ignore-project")
+        .arg("client");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("client/project").not())
+        .stdout(predicate::str::contains("other"));
+
+    Ok(())
+}
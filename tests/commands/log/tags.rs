@@ -16,20 +16,20 @@ fn get_only_correct_tag_entries() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         Some(vec!["test1".to_string()]),
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         Some(vec!["test2".to_string()]),
     )?;
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-T")
         .arg("test1");
@@ -51,21 +51,21 @@ fn get_only_correct_tags_entries() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         Some(vec!["test1".to_string()]),
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         Some(vec!["test2".to_string()]),
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-T")
         .arg("test1")
@@ -89,21 +89,21 @@ fn ignore_single_tag() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         Some(vec!["test2".to_string()]),
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--ignore-tag")
         .arg("test2");
@@ -125,21 +125,21 @@ fn ignore_multiple_tags() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         Some(vec!["test1".to_string()]),
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         Some(vec!["test2".to_string()]),
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--ignore-tag")
         .arg("test1")
@@ -159,7 +159,7 @@ fn ignore_and_select_tags() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-T")
         .arg("test1")
@@ -182,20 +182,20 @@ fn get_entries_from_multiple_tags() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test1",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         Some(vec!["test1".to_string(), "test3".to_string()]),
     )?;
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDateTime::from_timestamp(start + 3600, 0),
-        Some(&NaiveDateTime::from_timestamp(end + 3600, 0)),
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
         Some(vec!["test2".to_string(), "test3".to_string()]),
     )?;
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("-T")
         .arg("test3");
@@ -21,7 +21,7 @@ fn time_from_cannot_be_parsed() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn time_can_be_parsed_by_date() -> Result<(), Box<dyn std::error::Error>> {
-    let today = NaiveDate::pred(&Local::today().naive_local());
+    let today = Local::now().date_naive().pred_opt().unwrap();
 
     let mut cmd = Command::cargo_bin("aze")?;
     cmd.env("DATABASE_URL", "file::memory:?cache=shared")
@@ -41,7 +41,7 @@ fn time_can_be_parsed_by_date() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn time_can_be_parsed_by_datetime() -> Result<(), Box<dyn std::error::Error>> {
-    let today = NaiveDate::pred(&Local::today().naive_local());
+    let today = Local::now().date_naive().pred_opt().unwrap();
 
     let mut cmd = Command::cargo_bin("aze")?;
     cmd.env("DATABASE_URL", "file::memory:?cache=shared")
@@ -61,7 +61,7 @@ fn time_can_be_parsed_by_datetime() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn time_from_cannot_past_to() -> Result<(), Box<dyn std::error::Error>> {
-    let day_after_tomorrow = NaiveDate::succ(&Local::today().naive_local()).succ();
+    let day_after_tomorrow = Local::now().date_naive().succ_opt().unwrap().succ_opt().unwrap();
 
     let mut cmd = Command::cargo_bin("aze")?;
     cmd.env("DATABASE_URL", "file::memory:?cache=shared")
@@ -90,14 +90,14 @@ fn time_to_is_inclusive_tomorrow_default() -> Result<(), Box<dyn std::error::Err
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDateTime::from_timestamp(start, 0),
-        Some(&NaiveDateTime::from_timestamp(end, 0)),
+        "test",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
         None,
     )?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database).arg("log");
+    cmd.env("DATABASE_URL", database).arg("log");
 
     cmd.assert()
         .success()
@@ -110,13 +110,13 @@ fn time_to_is_inclusive_tomorrow_default() -> Result<(), Box<dyn std::error::Err
 fn time_from_searches_for_start() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
-    let start = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
-    let end = NaiveDate::from_ymd(2000, 1, 1).and_hms(13, 0, 0);
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
 
-    add_frame(&test_db, &"test", &start, Some(&end), None)?;
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--from")
         .arg("2000-01-01 12:30");
@@ -130,13 +130,13 @@ fn time_from_searches_for_start() -> Result<(), Box<dyn std::error::Error>> {
 fn time_from_includes() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
-    let start = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
-    let end = NaiveDate::from_ymd(2000, 1, 1).and_hms(13, 0, 0);
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
 
-    add_frame(&test_db, &"test", &start, Some(&end), None)?;
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--from")
         .arg("2000-01-01 11:00");
@@ -152,13 +152,13 @@ fn time_from_includes() -> Result<(), Box<dyn std::error::Error>> {
 fn time_to_includes() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
-    let start = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
-    let end = NaiveDate::from_ymd(2000, 1, 1).and_hms(13, 0, 0);
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
 
-    add_frame(&test_db, &"test", &start, Some(&end), None)?;
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--from")
         .arg("2000-01-01 11:00")
@@ -176,13 +176,13 @@ fn time_to_includes() -> Result<(), Box<dyn std::error::Error>> {
 fn time_to_searches_for_end() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
-    let start = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
-    let end = NaiveDate::from_ymd(2000, 1, 1).and_hms(13, 0, 0);
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
 
-    add_frame(&test_db, &"test", &start, Some(&end), None)?;
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
-    cmd.env("DATABASE_URL", &database)
+    cmd.env("DATABASE_URL", database)
         .arg("log")
         .arg("--from")
         .arg("2000-01-01 11:00")
@@ -193,3 +193,22 @@ fn time_to_searches_for_end() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn time_all_includes_old_entries() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+    let end = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(13, 0, 0).unwrap();
+
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("log").arg("--all");
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("test"));
+
+    Ok(())
+}
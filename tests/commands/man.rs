@@ -0,0 +1,65 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+#[test]
+fn man_page_of_aze() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path).arg("man");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".TH AZE 1"))
+        .stdout(predicate::str::contains(".SH \"DATE FORMATS\""))
+        .stdout(predicate::str::contains("aze\\-start(1)"))
+        .stdout(predicate::str::contains("__complete").not());
+
+    Ok(())
+}
+
+#[test]
+fn man_page_of_subcommand() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("man")
+        .arg("log");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".TH AZE-LOG 1"))
+        .stdout(predicate::str::contains(
+            "\\fB\\-p\\fR, \\fB\\-\\-project\\fR=\\fIPROJECTS\\fR",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn man_pages_written_to_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let dir = test_db.tmp_dir.path().join("man1");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .arg("man")
+        .arg("--dir")
+        .arg(&dir);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("man pages to"));
+
+    for page in ["aze.1", "aze-start.1", "aze-log.1", "aze-help.1"] {
+        assert!(dir.join(page).exists(), "{} is missing", page);
+    }
+    assert!(!dir.join("aze-__complete.1").exists());
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use crate::TestDb;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use diesel::RunQueryDsl;
 use aze::{
     database::MyJsonType,
@@ -9,14 +9,35 @@ use aze::{
 use uuid::Uuid;
 
 mod add;
+mod completions;
+mod config;
+mod doctor;
 mod edit;
 mod log;
+mod output;
+mod pomodoro;
 mod start;
 mod status;
 mod stop;
 mod frames;
+mod gaps;
+mod help;
+mod join;
+mod man;
+mod profile;
 mod projects;
 mod remove;
+mod search;
+mod shift;
+mod split;
+
+/// Time of the day on 2000-01-01, the day the frames of most tests are on.
+pub fn datetime(hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
 
 pub fn add_frame(
     test_db: &TestDb,
@@ -38,6 +59,7 @@ pub fn add_frame(
         project,
         tags: &tags,
         deleted: &false,
+        note: None,
     };
     let mut conn = test_db.conn().expect("error");
     diesel::insert_into(schema_frames::table)
@@ -0,0 +1,89 @@
+use assert_cmd::prelude::*;
+
+use chrono::Local;
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+use super::add_frame;
+
+fn add_finished_frame(test_db: &TestDb) -> Result<(), Box<dyn std::error::Error>> {
+    let start = Local::now().naive_local() - chrono::Duration::hours(2);
+
+    add_frame(
+        test_db,
+        "test",
+        &start,
+        Some(&(start + chrono::Duration::hours(1))),
+        None,
+    )
+}
+
+#[test]
+fn plain_output_when_not_a_terminal() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+    add_finished_frame(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env_remove("NO_COLOR")
+        .arg("log");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test"))
+        .stdout(predicate::str::contains("\u{1b}[").not());
+
+    Ok(())
+}
+
+#[test]
+fn colored_output_with_color_flag() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+    add_finished_frame(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env("NO_COLOR", "1")
+        .arg("log")
+        .arg("--color");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\u{1b}["));
+
+    Ok(())
+}
+
+#[test]
+fn no_color_flag_wins_over_config() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("color = \"always\"");
+    add_finished_frame(&test_db)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("--no-color")
+        .arg("log");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\u{1b}[").not());
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("log");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\u{1b}["));
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use assert_cmd::prelude::*;
+
+use chrono::{Duration, Local};
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::add_frame;
+
+#[test]
+fn pomodoro_records_work_intervals() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("pomodoro")
+        .arg("test")
+        .arg("+focus")
+        .arg("--work")
+        .arg("1s")
+        .arg("--break")
+        .arg("1s")
+        .arg("--cycles")
+        .arg("2");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Pomodoro 2/2"))
+        .stdout(predicate::str::contains("Finished 2 pomodoro(s)."));
+
+    let result = get_frames(&test_db);
+    assert_eq!(2, result.len());
+    for frame in result {
+        assert!(frame.end.is_some());
+        assert_eq!(
+            vec!["focus".to_string(), "pomodoro".to_string()],
+            frame.tags.values()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn pomodoro_with_running_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "another", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("pomodoro")
+        .arg("test")
+        .arg("--work")
+        .arg("1s");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Project another is already started",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn pomodoro_stops_running_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let start = Local::now().naive_local() - Duration::hours(1);
+    add_frame(&test_db, "another", &start, None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("pomodoro")
+        .arg("test")
+        .arg("--work")
+        .arg("1s")
+        .arg("-c")
+        .arg("1")
+        .arg("--stop");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project another"))
+        .stdout(predicate::str::contains("Finished 1 pomodoro(s)."));
+
+    let result = get_frames(&test_db);
+    assert_eq!(2, result.len());
+    assert!(result.iter().all(|frame| frame.end.is_some()));
+
+    Ok(())
+}
+
+#[test]
+fn pomodoro_collides_with_existing_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let now = Local::now().naive_local();
+    add_frame(
+        &test_db,
+        "another",
+        &(now - Duration::hours(1)),
+        Some(&(now + Duration::hours(1))),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("pomodoro")
+        .arg("test")
+        .arg("--work")
+        .arg("1s");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Start collides with existing frame",
+    ));
+    assert_eq!(1, get_frames(&test_db).len());
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+#[test]
+fn create_list_and_switch_profiles() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("profile")
+        .arg("create")
+        .arg("work");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Created profile work"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("profile")
+        .arg("switch")
+        .arg("work");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("profile")
+        .arg("list");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("  default\n"))
+        .stdout(predicate::str::contains("* work"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("config")
+        .arg("path");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("work.toml"));
+
+    Ok(())
+}
+
+#[test]
+fn profiles_use_separate_databases() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let data_dir = test_db.tmp_dir.path().join("data");
+    let config_home = test_db.config(&format!("data_dir = {:?}", data_dir.to_str().unwrap()));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env_remove("DATABASE_URL")
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("profile")
+        .arg("create")
+        .arg("side");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env_remove("DATABASE_URL")
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("--profile")
+        .arg("side")
+        .arg("start")
+        .arg("blog");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env_remove("DATABASE_URL")
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env("AZE_PROFILE", "side")
+        .arg("status");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Project blog started"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env_remove("DATABASE_URL")
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("status");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No project started"));
+
+    assert!(data_dir
+        .join("profiles")
+        .join("side")
+        .join("frames.db")
+        .exists());
+
+    Ok(())
+}
+
+#[test]
+fn unknown_profile() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("--profile")
+        .arg("missing")
+        .arg("status");
+    cmd.assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains("Unknown profile 'missing'"));
+
+    Ok(())
+}
+
+#[test]
+fn profile_names_must_not_be_paths() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let config_home = test_db.config("");
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .env("AZE_PROFILE", "../outside")
+        .arg("status");
+    cmd.assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains(
+            "Invalid profile name '../outside'",
+        ));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("profile")
+        .arg("create")
+        .arg("../outside");
+    cmd.assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains(
+            "Invalid profile name '../outside'",
+        ));
+
+    Ok(())
+}
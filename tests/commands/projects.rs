@@ -1,11 +1,10 @@
 use assert_cmd::prelude::*;
 
-use chrono::{Local, NaiveDateTime, Date, NaiveDate};
+use chrono::NaiveDate;
 use predicates::prelude::*;
-use std::{process::{Command, Stdio}, fs::File};
+use std::process::Command;
 
-use crate::{TestDb, commands::get_frames};
-use std::io::Read;
+use crate::TestDb;
 use super::add_frame;
 
 #[test]
@@ -28,16 +27,16 @@ fn projects_frame_saved() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11),
-        Option::from(&NaiveDate::from_ymd(2016, 7, 8).and_hms(10, 11, 12)),
+        "test",
+        &NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
         None
     )?;
 
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("projects");
+    cmd.env("DATABASE_URL", database).arg("projects");
 
     cmd.assert()
         .success()
@@ -53,24 +52,24 @@ fn projects_frames_saved() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11),
-        Option::from(&NaiveDate::from_ymd(2016, 7, 8).and_hms(10, 11, 12)),
+        "test",
+        &NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
         None
     )?;
 
     add_frame(
         &test_db,
-        &"test2",
-        &NaiveDate::from_ymd(2018, 7, 8).and_hms(9, 10, 11),
-        Option::from(&NaiveDate::from_ymd(2018, 7, 8).and_hms(10, 11, 12)),
+        "test2",
+        &NaiveDate::from_ymd_opt(2018, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2018, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
         None
     )?;
 
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("projects");
+    cmd.env("DATABASE_URL", database).arg("projects");
 
     cmd.assert()
         .success()
@@ -81,3 +80,40 @@ fn projects_frames_saved() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn projects_as_tree() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "client/project/task",
+        &NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
+        None
+    )?;
+
+    add_frame(
+        &test_db,
+        "client/other",
+        &NaiveDate::from_ymd_opt(2018, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2018, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
+        None
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.env("DATABASE_URL", database)
+        .arg("projects")
+        .arg("--tree");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("client  2h 02m 02s\n"))
+        .stdout(predicate::str::contains("├── other  1h 01m 01s"))
+        .stdout(predicate::str::contains("└── project  1h 01m 01s"))
+        .stdout(predicate::str::contains("    └── task  1h 01m 01s"));
+
+    Ok(())
+}
@@ -1,11 +1,9 @@
 use assert_cmd::prelude::*;
 
-use chrono::{Local, NaiveDateTime, Date, NaiveDate};
+use chrono::NaiveDate;
 use predicates::prelude::*;
-use std::{process::{Command, Stdio}, fs::File};
-use std::io::Write;
+use std::process::{Command, Stdio};
 use crate::{TestDb, commands::get_frames};
-use std::io::Read;
 use super::add_frame;
 
 #[test]
@@ -30,6 +28,7 @@ fn remove_with_id_nonexistent() -> Result<(), Box<dyn std::error::Error>> {
         .env("DATABASE_URL", "file::memory:?cache=shared");
     cmd.assert()
         .failure()
+        .code(3)
         .stderr(predicate::str::contains("No frame found with id aaaaaaa."));
 
     Ok(())
@@ -43,27 +42,30 @@ fn remove_with_id() -> Result<(), Box<dyn std::error::Error>> {
 
     add_frame(
         &test_db,
-        &"test",
-        &NaiveDate::from_ymd(2016, 7, 8).and_hms(9, 10, 11),
-        Option::from(&NaiveDate::from_ymd(2016, 7, 8).and_hms(10, 11, 12)),
+        "test",
+        &NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(9, 10, 11).unwrap(),
+        Option::from(&NaiveDate::from_ymd_opt(2016, 7, 8).unwrap().and_hms_opt(10, 11, 12).unwrap()),
         None
     )?;
 
     let result = get_frames(&test_db);
     assert_eq!(1, result.len());
-    assert_eq!(false, result.get(0).expect("fail").deleted);
+    assert!(!result.first().expect("fail").deleted);
 
-    let id = &result.get(0).expect("fail").id;
+    let id = &result.first().expect("fail").id;
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).stdin(Stdio::piped()).arg("remove").arg(id).arg("--force").unwrap();
-    cmd.assert()
-        .success();
+    cmd.env("DATABASE_URL", database)
+        .stdin(Stdio::piped())
+        .arg("remove")
+        .arg(id)
+        .arg("--force");
+    cmd.assert().success();
 
     let result = get_frames(&test_db);
     assert_eq!(1, result.len());
-    assert_eq!(true, result.get(0).expect("fail").deleted);
+    assert!(result.first().expect("fail").deleted);
 
     Ok(())
 }
@@ -0,0 +1,174 @@
+use assert_cmd::prelude::*;
+
+use chrono::{Local, NaiveDateTime};
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::TestDb;
+
+use super::add_frame;
+
+#[test]
+fn search_requires_query() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.arg("search")
+        .env("DATABASE_URL", "file::memory:?cache=shared");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("arguments were not provided"));
+
+    Ok(())
+}
+
+#[test]
+fn search_by_project_case_insensitive() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "Apollo11",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "hubble",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("search")
+        .arg("apollo");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Apollo11"))
+        .stdout(predicate::str::contains("hubble").not());
+
+    Ok(())
+}
+
+#[test]
+fn search_by_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        Some(vec!["review".to_string()]),
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
+        Some(vec!["meeting".to_string()]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("search")
+        .arg("REVIEW");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test1"))
+        .stdout(predicate::str::contains("test2").not());
+
+    Ok(())
+}
+
+#[test]
+fn search_with_regex() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 7200;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "voyager1",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "voyager",
+        &NaiveDateTime::from_timestamp_opt(start + 3600, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end + 3600, 0).unwrap()),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("search")
+        .arg("--regex")
+        .arg("^voyager[0-9]+$");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("voyager1"))
+        .stdout(predicate::str::contains("voyager\n").not());
+
+    Ok(())
+}
+
+#[test]
+fn search_with_invalid_regex() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.env("DATABASE_URL", "file::memory:?cache=shared")
+        .arg("search")
+        .arg("-e")
+        .arg("voyager(");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid regular expression"));
+
+    Ok(())
+}
+
+#[test]
+fn search_respects_time_range() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let start = Local::now().naive_local().timestamp() - 3600 * 24 * 10;
+    let end = start + 1800;
+
+    add_frame(
+        &test_db,
+        "test",
+        &NaiveDateTime::from_timestamp_opt(start, 0).unwrap(),
+        Some(&NaiveDateTime::from_timestamp_opt(end, 0).unwrap()),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("search").arg("test");
+    cmd.assert().success().stdout(predicate::str::is_empty());
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("search")
+        .arg("test")
+        .arg("--all");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("test"));
+
+    Ok(())
+}
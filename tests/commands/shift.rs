@@ -0,0 +1,175 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::{add_frame, datetime};
+
+#[test]
+fn shift_frame_by_id() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        None,
+    )?;
+    let id = get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("shift")
+        .arg(&id[..7])
+        .arg("--by")
+        .arg("+15m");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Shifted frame"));
+
+    let result = get_frames(&test_db);
+    assert_eq!(datetime(12, 15), result[0].start);
+    assert_eq!(Some(datetime(13, 15)), result[0].end);
+
+    Ok(())
+}
+
+#[test]
+fn shift_frames_in_range_backwards() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &datetime(13, 0),
+        Some(&datetime(14, 0)),
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("shift")
+        .arg("--from")
+        .arg("2000-01-01")
+        .arg("--to")
+        .arg("2000-01-02")
+        .arg("--by")
+        .arg("-1h");
+
+    cmd.assert().success();
+
+    let mut result = get_frames(&test_db);
+    result.sort_by_key(|frame| frame.start);
+    assert_eq!(datetime(11, 0), result[0].start);
+    assert_eq!(datetime(12, 0), result[1].start);
+
+    Ok(())
+}
+
+#[test]
+fn shift_rejects_overlap_with_untouched_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test1",
+        &datetime(12, 0),
+        Some(&datetime(13, 0)),
+        None,
+    )?;
+    add_frame(
+        &test_db,
+        "test2",
+        &datetime(13, 0),
+        Some(&datetime(14, 0)),
+        None,
+    )?;
+    let id = get_frames(&test_db)
+        .iter()
+        .find(|frame| frame.project == "test1")
+        .unwrap()
+        .id
+        .to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("shift")
+        .arg(&id[..7])
+        .arg("--by")
+        .arg("30m");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("would overlap with frame"));
+
+    let result = get_frames(&test_db);
+    assert!(result.iter().any(|frame| frame.start == datetime(12, 0)));
+
+    Ok(())
+}
+
+#[test]
+fn shift_requires_selection() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.env("DATABASE_URL", "file::memory:?cache=shared")
+        .arg("shift")
+        .arg("--by")
+        .arg("15m");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Either frame ids or --from/--to have to be given",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn shift_rejects_too_long_durations() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let start = datetime(12, 0);
+    let end = datetime(13, 0);
+    add_frame(&test_db, "test", &start, Some(&end), None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("shift")
+        .arg("--from")
+        .arg("2000-01-01")
+        .arg("--by")
+        .arg("99999999999999d");
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("is too long"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("shift")
+        .arg("--from")
+        .arg("2000-01-01")
+        .arg("--by")
+        .arg("-100000000d");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("would be moved too far"));
+
+    assert_eq!(start, get_frames(&test_db)[0].start);
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use assert_cmd::prelude::*;
+
+use predicates::prelude::*;
+use std::process::Command;
+
+use crate::{commands::get_frames, TestDb};
+
+use super::{add_frame, datetime};
+
+#[test]
+fn split_frame_in_two() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(14, 0)),
+        Some(vec!["tag1".to_string()]),
+    )?;
+    let id = get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("split")
+        .arg(&id[..7])
+        .arg("--at")
+        .arg("2000-01-01 13:00");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Split frame"));
+
+    let mut result = get_frames(&test_db);
+    result.sort_by_key(|frame| frame.start);
+    assert_eq!(2, result.len());
+    assert_eq!(Some(datetime(13, 0)), result[0].end);
+    assert_eq!(datetime(13, 0), result[1].start);
+    assert_eq!(Some(datetime(14, 0)), result[1].end);
+    assert_eq!("test", result[1].project);
+    assert_eq!(vec!["tag1".to_string()], result[1].tags.values());
+
+    Ok(())
+}
+
+#[test]
+fn split_frame_with_new_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(14, 0)),
+        Some(vec!["tag1".to_string()]),
+    )?;
+    let id = get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("split")
+        .arg(&id[..7])
+        .arg("+tag2")
+        .arg("--at")
+        .arg("2000-01-01 13:00")
+        .arg("--project")
+        .arg("other");
+
+    cmd.assert().success();
+
+    let mut result = get_frames(&test_db);
+    result.sort_by_key(|frame| frame.start);
+    assert_eq!("test", result[0].project);
+    assert_eq!("other", result[1].project);
+    assert_eq!(vec!["tag2".to_string()], result[1].tags.values());
+
+    Ok(())
+}
+
+#[test]
+fn split_frame_with_new_project_keeps_tags() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(14, 0)),
+        Some(vec!["tag1".to_string()]),
+    )?;
+    let id = get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("split")
+        .arg(&id[..7])
+        .arg("--at")
+        .arg("2000-01-01 13:00")
+        .arg("--project")
+        .arg("other");
+
+    cmd.assert().success();
+
+    let mut result = get_frames(&test_db);
+    result.sort_by_key(|frame| frame.start);
+    assert_eq!("other", result[1].project);
+    assert_eq!(vec!["tag1".to_string()], result[1].tags.values());
+
+    Ok(())
+}
+
+#[test]
+fn split_outside_of_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &datetime(12, 0),
+        Some(&datetime(14, 0)),
+        None,
+    )?;
+    let id = get_frames(&test_db).first().unwrap().id.to_string();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("split")
+        .arg(&id[..7])
+        .arg("--at")
+        .arg("2000-01-01 15:00");
+
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Split time must be between start and end",
+    ));
+
+    assert_eq!(1, get_frames(&test_db).len());
+
+    Ok(())
+}
@@ -4,19 +4,21 @@ use chrono::Local;
 use predicates::prelude::*;
 use std::process::Command;
 
-use crate::TestDb;
+use crate::{commands::get_frames, TestDb};
 
 use super::add_frame;
 
 #[test]
 fn start_requires_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
     let mut cmd = Command::cargo_bin("aze")?;
 
     cmd.arg("start")
+        .current_dir(test_db.tmp_dir.path())
         .env("DATABASE_URL", "file::memory:?cache=shared");
     cmd.assert()
         .failure()
-        .stderr(predicate::str::contains("arguments were not provided"));
+        .stderr(predicate::str::contains("No project given"));
 
     Ok(())
 }
@@ -42,7 +44,7 @@ fn start_with_project_but_already_started() -> Result<(), Box<dyn std::error::Er
 
     add_frame(
         &test_db,
-        &"another",
+        "another",
         &Local::now().naive_local(),
         None,
         None,
@@ -50,7 +52,7 @@ fn start_with_project_but_already_started() -> Result<(), Box<dyn std::error::Er
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("start").arg("test");
+    cmd.env("DATABASE_URL", database).arg("start").arg("test");
 
     cmd.assert().failure().stderr(predicate::str::contains(
         "Project another is already started",
@@ -75,3 +77,236 @@ fn start_project_with_tags() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn start_project_with_note() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+
+    cmd.env("DATABASE_URL", database)
+        .arg("start")
+        .arg("test")
+        .arg("-n")
+        .arg("ticket ABC-12");
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    assert_eq!(1, result.len());
+    assert_eq!(
+        Some("ticket ABC-12".to_string()),
+        result.first().unwrap().note
+    );
+
+    Ok(())
+}
+
+#[test]
+fn start_concurrent_project_when_allowed() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("allow_concurrent_frames = true");
+
+    add_frame(&test_db, "meeting", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("start")
+        .arg("build");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("start")
+        .arg("meeting");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Project meeting is already started",
+    ));
+
+    let result = get_frames(&test_db);
+    assert_eq!(2, result.iter().filter(|frame| frame.end.is_none()).count());
+
+    Ok(())
+}
+
+#[test]
+fn start_stops_running_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "another",
+        &(Local::now().naive_local() - chrono::Duration::hours(1)),
+        None,
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("start")
+        .arg("test")
+        .arg("--stop");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project another"))
+        .stdout(predicate::str::contains("starting project test"));
+
+    let result = get_frames(&test_db);
+    let stopped = result
+        .iter()
+        .find(|frame| frame.project == "another")
+        .unwrap();
+    let started = result.iter().find(|frame| frame.project == "test").unwrap();
+    assert_eq!(Some(started.start), stopped.end);
+    assert!(started.end.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn start_stops_running_frame_from_config() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("stop_on_start = true");
+
+    add_frame(
+        &test_db,
+        "another",
+        &(Local::now().naive_local() - chrono::Duration::hours(1)),
+        None,
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("start")
+        .arg("test")
+        .arg("--no-stop");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "Project another is already started",
+    ));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("start")
+        .arg("test");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project another"));
+
+    let result = get_frames(&test_db);
+    assert_eq!(1, result.iter().filter(|frame| frame.end.is_none()).count());
+
+    Ok(())
+}
+
+#[test]
+fn start_with_directory_defaults() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let repository = test_db.tmp_dir.path().join("repository");
+    let nested = repository.join("src");
+    std::fs::create_dir_all(&nested)?;
+    std::fs::write(
+        repository.join(".aze.toml"),
+        "project = \"apollo11\"\ntags = [\"module\"]",
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .current_dir(&nested)
+        .arg("start");
+    cmd.assert().success().stdout(predicate::str::contains(
+        "starting project apollo11 [module]",
+    ));
+
+    let result = get_frames(&test_db);
+    assert_eq!("apollo11", result[0].project);
+    assert_eq!(vec!["module".to_string()], result[0].tags.values());
+
+    Ok(())
+}
+
+#[test]
+fn start_with_directory_defaults_and_explicit_tags() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    std::fs::write(
+        test_db.tmp_dir.path().join(".aze.toml"),
+        "project = \"apollo11\"\ntags = [\"module\"]",
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .current_dir(test_db.tmp_dir.path())
+        .arg("start")
+        .arg("+review");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .current_dir(test_db.tmp_dir.path())
+        .arg("start")
+        .arg("other")
+        .arg("--stop");
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    let review = result
+        .iter()
+        .find(|frame| frame.project == "apollo11")
+        .unwrap();
+    assert_eq!(vec!["review".to_string()], review.tags.values());
+    let other = result
+        .iter()
+        .find(|frame| frame.project == "other")
+        .unwrap();
+    assert!(other.tags.values().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn start_with_invalid_directory_defaults() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    std::fs::write(test_db.tmp_dir.path().join(".aze.toml"), "project = [")?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", &test_db.db_path)
+        .current_dir(test_db.tmp_dir.path())
+        .arg("start")
+        .arg("+review");
+    cmd.assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("Invalid"))
+        .stderr(predicate::str::contains(".aze.toml"));
+
+    Ok(())
+}
+
+#[test]
+fn start_with_unusable_database() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let file = test_db.tmp_dir.path().join("file");
+    std::fs::write(&file, "")?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", file.join("data").join("frames.db"))
+        .arg("start")
+        .arg("apollo11");
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "Could not create the data directory",
+        ));
+
+    Ok(())
+}
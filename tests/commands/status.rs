@@ -26,11 +26,11 @@ fn status_project_started() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
 
-    add_frame(&test_db, &"test", &Local::now().naive_local(), None, None)?;
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("status");
+    cmd.env("DATABASE_URL", database).arg("status");
 
     cmd.assert()
         .success()
@@ -38,3 +38,229 @@ fn status_project_started() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn status_lists_all_running_frames() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "meeting", &Local::now().naive_local(), None, None)?;
+    add_frame(&test_db, "build", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("status").arg("-p");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("meeting"))
+        .stdout(predicate::str::contains("build"));
+
+    Ok(())
+}
+
+#[test]
+fn status_warns_about_idle_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("max_frame_duration = \"8h\"");
+
+    add_frame(
+        &test_db,
+        "test",
+        &(Local::now().naive_local() - chrono::Duration::hours(14)),
+        None,
+        None,
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("status");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("aze stop --idle"));
+
+    Ok(())
+}
+
+#[test]
+fn status_watch_redraws_until_interrupted() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &(Local::now().naive_local() - chrono::Duration::minutes(5)),
+        None,
+        None,
+    )?;
+
+    let mut cmd = assert_cmd::Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--watch")
+        .timeout(std::time::Duration::from_millis(1500));
+
+    cmd.assert()
+        .interrupted()
+        .stdout(predicate::str::contains("00:05:0"))
+        .stdout(predicate::str::contains("Today"))
+        .stdout(predicate::str::contains("\x1b[2J").not());
+
+    Ok(())
+}
+
+#[test]
+fn status_watch_counts_today_of_frames_crossing_midnight() -> Result<(), Box<dyn std::error::Error>>
+{
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let midnight = Local::now().date_naive().and_hms_opt(0, 0, 0).unwrap();
+
+    add_frame(
+        &test_db,
+        "night",
+        &(midnight - chrono::Duration::hours(2)),
+        Some(&(midnight + chrono::Duration::minutes(1))),
+        None,
+    )?;
+
+    let mut cmd = assert_cmd::Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--watch")
+        .timeout(std::time::Duration::from_millis(1500));
+
+    cmd.assert()
+        .interrupted()
+        .stdout(predicate::str::contains("night\t00:01:00"));
+
+    Ok(())
+}
+
+#[test]
+fn status_with_format() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(
+        &test_db,
+        "test",
+        &(Local::now().naive_local() - chrono::Duration::minutes(90)),
+        None,
+        Some(vec!["tag1".to_string()]),
+    )?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--format")
+        .arg("{project} [{tags}] {elapsed:m}");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("test [tag1] 90\n"));
+
+    Ok(())
+}
+
+#[test]
+fn status_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--json");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("[{\"id\":"))
+        .stdout(predicate::str::contains("\"project\":\"test\""))
+        .stdout(predicate::str::contains("\"elapsed\":"));
+
+    Ok(())
+}
+
+#[test]
+fn status_with_format_fails_quietly_without_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--format")
+        .arg("{project}");
+
+    cmd.assert()
+        .code(6)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn status_with_invalid_start_format() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--format")
+        .arg("{start:%Q}");
+
+    cmd.assert().code(1).stderr(predicate::str::contains(
+        "Invalid date format in {start:%Q}",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn status_for_waybar() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--bar")
+        .arg("waybar");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("{\"text\":\"test 00:00:0"))
+        .stdout(predicate::str::contains("\"class\":\"running\""));
+
+    Ok(())
+}
+
+#[test]
+fn status_for_i3blocks_without_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("status")
+        .arg("--bar")
+        .arg("i3blocks");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::diff("idle\nidle\n#808080\n"));
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use assert_cmd::prelude::*;
 
-use chrono::Local;
+use chrono::{Local, NaiveDate, Timelike};
 use predicates::prelude::*;
 use std::process::Command;
 
@@ -16,6 +16,7 @@ fn stop_no_project_started() -> Result<(), Box<dyn std::error::Error>> {
         .env("DATABASE_URL", "file::memory:?cache=shared");
     cmd.assert()
         .failure()
+        .code(6)
         .stderr(predicate::str::contains("No project started"));
 
     Ok(())
@@ -26,11 +27,11 @@ fn stop_is_stopping_project() -> Result<(), Box<dyn std::error::Error>> {
     let test_db = TestDb::new();
     let database = &test_db.db_path;
 
-    add_frame(&test_db, &"test", &Local::now().naive_local(), None, None)?;
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
 
     let mut cmd = Command::cargo_bin("aze")?;
 
-    cmd.env("DATABASE_URL", &database).arg("stop");
+    cmd.env("DATABASE_URL", database).arg("stop");
 
     cmd.assert()
         .success()
@@ -38,7 +39,204 @@ fn stop_is_stopping_project() -> Result<(), Box<dyn std::error::Error>> {
 
     let result = get_frames(&test_db);
     assert_eq!(1, result.len());
-    assert!(result.get(0).unwrap().end.is_some());
+    assert!(result.first().unwrap().end.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn stop_appends_note() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("start")
+        .arg("test")
+        .arg("--note")
+        .arg("ticket ABC-12");
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("stop")
+        .arg("-n")
+        .arg("finished review");
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    assert_eq!(1, result.len());
+    assert_eq!(
+        Some("ticket ABC-12; finished review".to_string()),
+        result.first().unwrap().note
+    );
+
+    Ok(())
+}
+
+#[test]
+fn stop_running_frame_by_project() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("allow_concurrent_frames = true");
+
+    add_frame(&test_db, "meeting", &Local::now().naive_local(), None, None)?;
+    add_frame(&test_db, "build", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("stop");
+    cmd.assert()
+        .failure()
+        .code(7)
+        .stderr(predicate::str::contains("Several frames are running"));
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("stop")
+        .arg("build");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project build"));
+
+    let result = get_frames(&test_db);
+    let running: Vec<_> = result.iter().filter(|frame| frame.end.is_none()).collect();
+    assert_eq!(1, running.len());
+    assert_eq!("meeting", running[0].project);
+
+    Ok(())
+}
+
+#[test]
+fn stop_unknown_frame() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database).arg("stop").arg("other");
+    cmd.assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains("No running frame matches other."));
+
+    Ok(())
+}
+
+#[test]
+fn stop_idle_frame_at_maximum_duration() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("max_frame_duration = \"8h\"");
+    let start = (Local::now().naive_local() - chrono::Duration::hours(14))
+        .with_nanosecond(0)
+        .unwrap();
+
+    add_frame(&test_db, "test", &start, None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("stop")
+        .arg("--idle");
+    cmd.assert().success();
+
+    let result = get_frames(&test_db);
+    assert_eq!(Some(start + chrono::Duration::hours(8)), result[0].end);
+
+    Ok(())
+}
+
+#[test]
+fn stop_idle_requires_maximum_duration() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("");
+
+    add_frame(&test_db, "test", &Local::now().naive_local(), None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("stop")
+        .arg("--idle");
+    cmd.assert().failure().stderr(predicate::str::contains(
+        "No max_frame_duration configured.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn stop_at_positional_time() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+
+    let start = NaiveDate::from_ymd_opt(2000, 1, 3)
+        .unwrap()
+        .and_hms_opt(10, 0, 0)
+        .unwrap();
+    add_frame(&test_db, "test", &start, None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .arg("stop")
+        .arg("2000-01-03 12:00");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project test"));
+
+    let end = NaiveDate::from_ymd_opt(2000, 1, 3)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    assert_eq!(Some(end), get_frames(&test_db)[0].end);
+
+    Ok(())
+}
+
+#[test]
+fn stop_running_frame_by_project_at_time() -> Result<(), Box<dyn std::error::Error>> {
+    let test_db = TestDb::new();
+    let database = &test_db.db_path;
+    let config_home = test_db.config("allow_concurrent_frames = true");
+
+    let start = NaiveDate::from_ymd_opt(2000, 1, 3)
+        .unwrap()
+        .and_hms_opt(10, 0, 0)
+        .unwrap();
+    add_frame(&test_db, "meeting", &start, None, None)?;
+    add_frame(&test_db, "build", &start, None, None)?;
+
+    let mut cmd = Command::cargo_bin("aze")?;
+    cmd.env("DATABASE_URL", database)
+        .env("XDG_CONFIG_HOME", &config_home)
+        .arg("stop")
+        .arg("build")
+        .arg("--at")
+        .arg("2000-01-03 12:00");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Stopping project build"));
+
+    let end = NaiveDate::from_ymd_opt(2000, 1, 3)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    let result = get_frames(&test_db);
+    let build = result
+        .iter()
+        .find(|frame| frame.project == "build")
+        .unwrap();
+    let meeting = result
+        .iter()
+        .find(|frame| frame.project == "meeting")
+        .unwrap();
+    assert_eq!(Some(end), build.end);
+    assert_eq!(None, meeting.end);
 
     Ok(())
 }